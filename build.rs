@@ -2,13 +2,39 @@ use std::path::{Path, PathBuf};
 
 /// The Spout2 fork that does not include precompiled `dll`s and `lib`s.
 const SPOUT_DIR: &str = "Spout2-lean";
-const SPOUT_TAG: &str = "2.007.011";
+/// The Spout release the bindings are written against.
+const DEFAULT_SPOUT_TAG: &str = "2.007.011";
+/// Overrides [DEFAULT_SPOUT_TAG]. Only 2.007 releases are supported.
+const SPOUT_TAG_VAR: &str = "SPOUT_TAG";
+/// Install prefix of a prebuilt SpoutLibrary, used with the `prebuilt` feature.
+const SPOUT_LIB_DIR_VAR: &str = "SPOUT_LIB_DIR";
 
 fn main() {
     let repo_root = Path::new(env!("CARGO_MANIFEST_DIR"));
 
-    ensure_spout_initted();
-    let (spout_build_dir, lib_dir) = build_spout();
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let tag = std::env::var(SPOUT_TAG_VAR).unwrap_or_else(|_| DEFAULT_SPOUT_TAG.to_string());
+    // Embedded as `SPOUT_BUILT_VERSION` for comparing against the Spout found at runtime
+    println!("cargo:rustc-env=SPOUT_BUILT_VERSION={tag}");
+    println!("cargo:rerun-if-env-changed={SPOUT_TAG_VAR}");
+
+    // Spout is Windows-only, so there is nothing to build or link. The crate falls back to a
+    // stub that compiles but never gets a Spout handle
+    let target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    if target_os != "windows" {
+        println!(
+            "cargo:warning=Spout only supports Windows, building a non-functional stub for {target_os}"
+        );
+        return;
+    }
+
+    let (spout_build_dir, lib_dir) = if std::env::var_os("CARGO_FEATURE_PREBUILT").is_some() {
+        prebuilt_spout()
+    } else {
+        ensure_spout_initted(repo_root, &tag);
+        build_spout(repo_root)
+    };
 
     if let Err(e) = std::fs::write(
         repo_root.join("_spout_dll_path"),
@@ -30,34 +56,73 @@ fn main() {
         .flag_if_supported("-std=c++14")
         .compile("spoutlib");
 
-    println!("cargo:rerun-if-changed=build.rs");
     println!("cargo:rerun-if-changed=lib.rs");
+    println!("cargo:rerun-if-env-changed={SPOUT_LIB_DIR_VAR}");
 
     println!("cargo:rustc-link-lib=SpoutLibrary");
     println!("cargo:rustc-link-search=native={}", lib_dir.display());
 }
 
-fn ensure_spout_initted() {
-    // TODO this might not be the correct path
-    if !Path::new(SPOUT_DIR).exists() {
-        // TODO explode
-        // if env!("CARGO_NET_OFFLINE ") {
-        //     panic!("")
-        // }
+/// Make sure the Spout2 submodule is checked out, initializing it with git if needed.
+///
+/// `tag` is always checked out, even if it is [DEFAULT_SPOUT_TAG], so that a tag left checked out
+/// by an earlier build with a different `SPOUT_TAG` is not built by mistake.
+fn ensure_spout_initted(repo_root: &Path, tag: &str) {
+    // An uninitialized submodule is an empty directory
+    let is_initted = |dir: &Path| dir.join("CMakeLists.txt").is_file();
+
+    let spout_dir = repo_root.join(SPOUT_DIR);
+    if is_initted(&spout_dir) {
+        checkout_spout_tag(&spout_dir, tag);
+        return;
+    }
+
+    let help = format!(
+        "Unable to init the {SPOUT_DIR} submodule for Spout {tag}. Run \
+        `git submodule update --init {SPOUT_DIR}` manually, or enable the `prebuilt` feature \
+        and set {SPOUT_LIB_DIR_VAR}"
+    );
+
+    let status = std::process::Command::new("git")
+        .args(["submodule", "update", "--init", SPOUT_DIR])
+        .current_dir(repo_root)
+        .status();
+
+    match status {
+        Ok(v) if v.success() && is_initted(&spout_dir) => {}
+        Ok(v) => panic!("{help} (git exited with {v})"),
+        Err(e) => panic!("{help} ({e})"),
+    }
 
-        let status = std::process::Command::new("git")
-            .args(["submodule", "update", "--init", SPOUT_TAG])
+    checkout_spout_tag(&spout_dir, tag);
+}
+
+/// Check out `tag` in the Spout2 submodule, fetching tags if it is not known yet.
+fn checkout_spout_tag(spout_dir: &Path, tag: &str) {
+    let git = |args: &[&str]| {
+        std::process::Command::new("git")
+            .args(args)
+            .current_dir(spout_dir)
             .status()
-            .unwrap();
+            .map_or(false, |v| v.success())
+    };
 
-        if !status.success() {
-            panic!("Unable to init Spout2 submodule");
-        }
+    let tag_ref = format!("tags/{tag}");
+    if git(&["checkout", "--quiet", &tag_ref]) {
+        return;
     }
+    if git(&["fetch", "--quiet", "--tags"]) && git(&["checkout", "--quiet", &tag_ref]) {
+        return;
+    }
+
+    panic!(
+        "Unable to check out Spout {tag} in {SPOUT_DIR}. Make sure {SPOUT_TAG_VAR} is a tag of \
+        Spout2, or unset it to use {DEFAULT_SPOUT_TAG}"
+    );
 }
 
-fn build_spout() -> (PathBuf, PathBuf) {
-    let dst = cmake::Config::new(SPOUT_DIR)
+fn build_spout(repo_root: &Path) -> (PathBuf, PathBuf) {
+    let dst = cmake::Config::new(repo_root.join(SPOUT_DIR))
         .define("SKIP_INSTALL_ALL", "OFF")
         .define("SKIP_INSTALL_HEADERS", "OFF")
         .define("SKIP_INSTALL_LIBRARIES", "OFF")
@@ -70,3 +135,28 @@ fn build_spout() -> (PathBuf, PathBuf) {
 
     (dst.clone(), dst.join("lib"))
 }
+
+/// Find a prebuilt SpoutLibrary instead of building it.
+///
+/// `SPOUT_LIB_DIR` must point to a directory with the same layout that the cmake build installs:
+/// * `include/SpoutLibrary/SpoutLibrary.h`
+/// * `lib/SpoutLibrary.lib`
+/// * `bin/SpoutLibrary.dll`
+fn prebuilt_spout() -> (PathBuf, PathBuf) {
+    let dir = match std::env::var_os(SPOUT_LIB_DIR_VAR) {
+        Some(v) => PathBuf::from(v),
+        None => panic!("The prebuilt feature requires {SPOUT_LIB_DIR_VAR} to be set"),
+    };
+
+    let lib_dir = dir.join("lib");
+    for file in [
+        dir.join("include/SpoutLibrary/SpoutLibrary.h"),
+        lib_dir.join("SpoutLibrary.lib"),
+    ] {
+        if !file.is_file() {
+            panic!("{} does not exist in {SPOUT_LIB_DIR_VAR}", file.display());
+        }
+    }
+
+    (dir, lib_dir)
+}
@@ -0,0 +1,19 @@
+use rusty_spout;
+
+fn main() {
+    let mut rs = rusty_spout::RustySpout::new();
+
+    rs.get_spout().expect("unable to get spout pointer");
+
+    let stdin = std::io::stdin();
+    match rs
+        .pick_sender_blocking(stdin.lock(), std::io::stdout())
+        .expect("pick_sender_blocking")
+    {
+        Some(name) => {
+            println!("picked {name}");
+            rs.set_receiver_name(&name).expect("set_receiver_name");
+        }
+        None => println!("no sender picked"),
+    }
+}
@@ -0,0 +1,107 @@
+//! Receive an OpenGL texture sent by the `send_texture` example.
+
+#[cfg(windows)]
+mod gl {
+    pub const GL_UNSIGNED_BYTE: u32 = 0x1401;
+
+    #[link(name = "opengl32")]
+    extern "system" {
+        pub fn glGenTextures(n: i32, textures: *mut u32);
+        pub fn glDeleteTextures(n: i32, textures: *const u32);
+        pub fn glBindTexture(target: u32, texture: u32);
+        pub fn glTexImage2D(
+            target: u32,
+            level: i32,
+            internal_format: i32,
+            width: i32,
+            height: i32,
+            border: i32,
+            format: u32,
+            type_: u32,
+            pixels: *const std::ffi::c_void,
+        );
+    }
+}
+
+#[cfg(windows)]
+fn main() {
+    use std::time::Duration;
+
+    use rusty_spout::{GL_RGBA, GL_TEXTURE_2D};
+
+    const FRAMES: u32 = 600;
+
+    let mut rs = rusty_spout::RustySpout::new();
+
+    rs.get_spout().expect("unable to get spout pointer");
+
+    // Spout needs a current OpenGL context to share textures
+    if !rs.create_opengl().expect("create_opengl") {
+        panic!("unable to create an OpenGL context");
+    }
+
+    rs.set_receiver_name("texture").expect("set_receiver_name");
+
+    let mut texture = 0;
+    unsafe {
+        gl::glGenTextures(1, &mut texture);
+    }
+
+    let mut received = 0;
+    while received < FRAMES {
+        match rs.receive_texture(texture.into(), GL_TEXTURE_2D, false, 0.into()) {
+            Ok(true) => {}
+            Ok(false) => {
+                // Not connected yet, wait for the sender to start
+                std::thread::sleep(Duration::from_millis(100));
+                continue;
+            }
+            Err(e) => panic!("{e}"),
+        }
+
+        // The sender started or changed size, so the texture has to match it before the next
+        // receive can copy into it
+        if rs.is_updated().expect("is_updated") {
+            let width = rs.get_sender_width().expect("get_sender_width");
+            let height = rs.get_sender_height().expect("get_sender_height");
+            println!("connected to {width}x{height} sender");
+
+            unsafe {
+                gl::glBindTexture(GL_TEXTURE_2D.0, texture);
+                gl::glTexImage2D(
+                    GL_TEXTURE_2D.0,
+                    0,
+                    GL_RGBA.0 as i32,
+                    width as i32,
+                    height as i32,
+                    0,
+                    GL_RGBA.0,
+                    gl::GL_UNSIGNED_BYTE,
+                    std::ptr::null(),
+                );
+                gl::glBindTexture(GL_TEXTURE_2D.0, 0);
+            }
+            continue;
+        }
+
+        received += 1;
+        println!(
+            "received frame {}",
+            rs.get_sender_frame().expect("get_sender_frame")
+        );
+
+        std::thread::sleep(Duration::from_millis(16));
+    }
+
+    unsafe {
+        gl::glDeleteTextures(1, &texture);
+    }
+
+    rs.release_receiver().expect("release_receiver");
+    rs.close_opengl().expect("close_opengl");
+}
+
+#[cfg(not(windows))]
+fn main() {
+    eprintln!("receiving textures needs OpenGL through Spout, which is Windows-only");
+}
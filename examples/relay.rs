@@ -0,0 +1,29 @@
+use std::time::Duration;
+
+use rusty_spout;
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let from = args.next().unwrap_or_else(|| "test".to_string());
+    let to = args.next().unwrap_or_else(|| "relay".to_string());
+
+    let mut rs = rusty_spout::RustySpout::new();
+
+    rs.get_spout().expect("unable to get spout pointer");
+
+    if !rs.create_opengl().expect("create_opengl") {
+        panic!("unable to create an OpenGL context");
+    }
+
+    println!("relaying {from} to {to}");
+
+    loop {
+        match rs.relay(&from, &to) {
+            Ok(true) => {}
+            Ok(false) => std::thread::sleep(Duration::from_millis(100)),
+            Err(e) => panic!("{e}"),
+        }
+
+        std::thread::sleep(Duration::from_millis(16));
+    }
+}
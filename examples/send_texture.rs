@@ -0,0 +1,97 @@
+//! Send an OpenGL texture. Run `receive_texture` at the same time to see it arrive.
+
+#[cfg(windows)]
+mod gl {
+    pub const GL_UNSIGNED_BYTE: u32 = 0x1401;
+
+    #[link(name = "opengl32")]
+    extern "system" {
+        pub fn glGenTextures(n: i32, textures: *mut u32);
+        pub fn glDeleteTextures(n: i32, textures: *const u32);
+        pub fn glBindTexture(target: u32, texture: u32);
+        pub fn glTexImage2D(
+            target: u32,
+            level: i32,
+            internal_format: i32,
+            width: i32,
+            height: i32,
+            border: i32,
+            format: u32,
+            type_: u32,
+            pixels: *const std::ffi::c_void,
+        );
+    }
+}
+
+#[cfg(windows)]
+fn main() {
+    use rusty_spout::{TestPattern, GL_RGBA, GL_TEXTURE_2D};
+
+    const WIDTH: u32 = 640;
+    const HEIGHT: u32 = 360;
+    const FRAMES: u32 = 600;
+
+    let mut rs = rusty_spout::RustySpout::new();
+
+    rs.get_spout().expect("unable to get spout pointer");
+
+    // Spout needs a current OpenGL context to share textures
+    if !rs.create_opengl().expect("create_opengl") {
+        panic!("unable to create an OpenGL context");
+    }
+
+    rs.set_sender_name("texture").expect("set_sender_name");
+
+    let mut texture = 0;
+    unsafe {
+        gl::glGenTextures(1, &mut texture);
+    }
+
+    for frame in 0..FRAMES {
+        let color = [(frame % 256) as u8, 128, 255 - (frame % 256) as u8, 255];
+        let pixels = TestPattern::SolidColor(color).pixels(WIDTH, HEIGHT);
+
+        unsafe {
+            gl::glBindTexture(GL_TEXTURE_2D.0, texture);
+            gl::glTexImage2D(
+                GL_TEXTURE_2D.0,
+                0,
+                GL_RGBA.0 as i32,
+                WIDTH as i32,
+                HEIGHT as i32,
+                0,
+                GL_RGBA.0,
+                gl::GL_UNSIGNED_BYTE,
+                pixels.as_ptr().cast(),
+            );
+            gl::glBindTexture(GL_TEXTURE_2D.0, 0);
+        }
+
+        match rs.send_texture(
+            texture.into(),
+            GL_TEXTURE_2D,
+            WIDTH,
+            HEIGHT,
+            false,
+            0.into(),
+        ) {
+            Ok(true) => println!("sent frame {frame}"),
+            Ok(false) => eprintln!("unable to send frame {frame}"),
+            Err(e) => panic!("{e}"),
+        }
+
+        rs.hold_fps(60).expect("hold_fps");
+    }
+
+    unsafe {
+        gl::glDeleteTextures(1, &texture);
+    }
+
+    rs.release_sender(0.into()).expect("release_sender");
+    rs.close_opengl().expect("close_opengl");
+}
+
+#[cfg(not(windows))]
+fn main() {
+    eprintln!("sending textures needs OpenGL through Spout, which is Windows-only");
+}
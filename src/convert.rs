@@ -0,0 +1,344 @@
+//! CPU conversions between 8-bit [PixelFormat]s.
+//!
+//! Every conversion takes the `stride` of the source, the number of bytes from the start of one
+//! row to the start of the next, so padded rows can be read in place. Use
+//! [PixelFormat::buffer_size] with a height of 1 for tightly packed pixels. The output is always
+//! tightly packed.
+//!
+//! Pixels are processed a row at a time in fixed-size chunks, which the compiler can vectorize.
+
+use crate::{Error, PixelFormat, Result};
+
+/// Convert pixels from one 8-bit [PixelFormat] to another. A missing alpha channel is filled
+/// with 255 and a dropped one is discarded.
+///
+/// An [Error::UnexpectedValue] is returned for float formats, or if `src` is too small for the
+/// dimensions and `stride`.
+pub fn convert(
+    src: &[u8],
+    width: u32,
+    height: u32,
+    stride: usize,
+    from: PixelFormat,
+    to: PixelFormat,
+) -> Result<Vec<u8>> {
+    from.ensure_8_bit("convert")?;
+    to.ensure_8_bit("convert")?;
+
+    let row_len = from.buffer_size(width, 1);
+    check_layout(src.len(), row_len, height, stride)?;
+
+    let mut dst = vec![0; to.buffer_size(width, height)];
+    if dst.is_empty() {
+        return Ok(dst);
+    }
+
+    for (src_row, dst_row) in src
+        .chunks(stride)
+        .zip(dst.chunks_exact_mut(to.buffer_size(width, 1)))
+    {
+        convert_row(&src_row[..row_len], dst_row, from, to);
+    }
+
+    Ok(dst)
+}
+
+/// Convert [PixelFormat::Rgba] pixels to [PixelFormat::Bgra].
+///
+/// See [convert] for the errors returned.
+pub fn rgba_to_bgra(src: &[u8], width: u32, height: u32, stride: usize) -> Result<Vec<u8>> {
+    convert(
+        src,
+        width,
+        height,
+        stride,
+        PixelFormat::Rgba,
+        PixelFormat::Bgra,
+    )
+}
+
+/// Convert [PixelFormat::Bgra] pixels to [PixelFormat::Rgba].
+///
+/// See [convert] for the errors returned.
+pub fn bgra_to_rgba(src: &[u8], width: u32, height: u32, stride: usize) -> Result<Vec<u8>> {
+    convert(
+        src,
+        width,
+        height,
+        stride,
+        PixelFormat::Bgra,
+        PixelFormat::Rgba,
+    )
+}
+
+/// Convert [PixelFormat::Rgb] pixels to [PixelFormat::Rgba] with an opaque alpha channel.
+///
+/// See [convert] for the errors returned.
+pub fn rgb_to_rgba(src: &[u8], width: u32, height: u32, stride: usize) -> Result<Vec<u8>> {
+    convert(
+        src,
+        width,
+        height,
+        stride,
+        PixelFormat::Rgb,
+        PixelFormat::Rgba,
+    )
+}
+
+/// Flip an image upside down in place by swapping its rows.
+///
+/// Useful for CPU pixels when the receiver shows frames upside down, since OpenGL images start at
+/// the bottom row while most image libraries start at the top.
+///
+/// # Panics
+/// Panics if `buf` is smaller than `width * height * bytes_per_pixel`.
+pub fn flip_vertical(buf: &mut [u8], width: u32, height: u32, bytes_per_pixel: usize) {
+    let row_len = width as usize * bytes_per_pixel;
+    let height = height as usize;
+    assert!(
+        buf.len() >= row_len * height,
+        "flip_vertical: expected at least {} bytes, got {}",
+        row_len * height,
+        buf.len()
+    );
+    if row_len == 0 {
+        return;
+    }
+
+    let (top, bottom) = buf[..row_len * height].split_at_mut(row_len * (height / 2));
+    // The middle row of an odd height stays where it is
+    let bottom = &mut bottom[row_len * (height % 2)..];
+    for (top_row, bottom_row) in top
+        .chunks_exact_mut(row_len)
+        .zip(bottom.chunks_exact_mut(row_len).rev())
+    {
+        top_row.swap_with_slice(bottom_row);
+    }
+}
+
+/// Return an [Error::UnexpectedValue] if `len` bytes cannot hold `height` rows of `row_len` bytes
+/// that start `stride` bytes apart.
+fn check_layout(len: usize, row_len: usize, height: u32, stride: usize) -> Result<()> {
+    if stride < row_len {
+        return Err(Error::UnexpectedValue {
+            context: format!("convert: stride {stride} is smaller than a row of {row_len} bytes"),
+        });
+    }
+
+    // The last row does not need any padding after it
+    let needed = match height {
+        0 => 0,
+        _ => stride * (height as usize - 1) + row_len,
+    };
+    if len < needed {
+        return Err(Error::UnexpectedValue {
+            context: format!("convert: expected at least {needed} bytes, got {len}"),
+        });
+    }
+
+    Ok(())
+}
+
+/// Convert a single tightly packed row.
+fn convert_row(src: &[u8], dst: &mut [u8], from: PixelFormat, to: PixelFormat) {
+    match (from, to) {
+        _ if from == to => dst.copy_from_slice(src),
+        // Swapping red and blue is by far the most common conversion, so it gets a path with
+        // constant offsets
+        (PixelFormat::Rgba, PixelFormat::Bgra) | (PixelFormat::Bgra, PixelFormat::Rgba) => {
+            for (s, d) in src.chunks_exact(4).zip(dst.chunks_exact_mut(4)) {
+                d.copy_from_slice(&[s[2], s[1], s[0], s[3]]);
+            }
+        }
+        (PixelFormat::Rgb, PixelFormat::Rgba) | (PixelFormat::Bgr, PixelFormat::Bgra) => {
+            for (s, d) in src.chunks_exact(3).zip(dst.chunks_exact_mut(4)) {
+                d.copy_from_slice(&[s[0], s[1], s[2], 255]);
+            }
+        }
+        _ => {
+            let (from_r, from_g, from_b, from_a) = from.channel_offsets();
+            let (to_r, to_g, to_b, to_a) = to.channel_offsets();
+
+            for (s, d) in src
+                .chunks_exact(from.bytes_per_pixel())
+                .zip(dst.chunks_exact_mut(to.bytes_per_pixel()))
+            {
+                d[to_r] = s[from_r];
+                d[to_g] = s[from_g];
+                d[to_b] = s[from_b];
+                if let Some(to_a) = to_a {
+                    d[to_a] = from_a.map_or(255, |from_a| s[from_a]);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One byte per pixel rows, each filled with its row index.
+    fn rows(width: usize, height: usize) -> Vec<u8> {
+        (0..height).flat_map(|y| vec![y as u8; width]).collect()
+    }
+
+    #[test]
+    fn flip_even_height() {
+        let mut buf = rows(3, 4);
+        flip_vertical(&mut buf, 3, 4, 1);
+
+        assert_eq!(buf, [3, 3, 3, 2, 2, 2, 1, 1, 1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn flip_odd_height() {
+        let mut buf = rows(2, 3);
+        flip_vertical(&mut buf, 2, 3, 1);
+
+        assert_eq!(buf, [2, 2, 1, 1, 0, 0]);
+    }
+
+    #[test]
+    fn flip_multi_byte_pixels() {
+        let mut buf = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        flip_vertical(&mut buf, 1, 2, 4);
+
+        assert_eq!(buf, [5, 6, 7, 8, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn flip_height_0_and_1() {
+        let mut buf = vec![];
+        flip_vertical(&mut buf, 4, 0, 4);
+        assert!(buf.is_empty());
+
+        let mut buf = vec![1, 2, 3, 4];
+        flip_vertical(&mut buf, 4, 1, 1);
+        assert_eq!(buf, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn flip_leaves_trailing_bytes() {
+        let mut buf = vec![0, 1, 9];
+        flip_vertical(&mut buf, 1, 2, 1);
+
+        assert_eq!(buf, [1, 0, 9]);
+    }
+
+    #[test]
+    #[should_panic(expected = "flip_vertical")]
+    fn flip_short_buffer_panics() {
+        let mut buf = vec![0; 5];
+        flip_vertical(&mut buf, 2, 3, 1);
+    }
+
+    const RGBA: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+    const BGRA: [u8; 8] = [3, 2, 1, 4, 7, 6, 5, 8];
+
+    #[test]
+    fn rgba_to_bgra_swaps_red_and_blue() {
+        assert_eq!(rgba_to_bgra(&RGBA, 2, 1, 8).unwrap(), BGRA);
+    }
+
+    #[test]
+    fn bgra_to_rgba_swaps_red_and_blue() {
+        assert_eq!(bgra_to_rgba(&BGRA, 2, 1, 8).unwrap(), RGBA);
+    }
+
+    #[test]
+    fn rgb_to_rgba_adds_opaque_alpha() {
+        let rgb = [1, 2, 3, 4, 5, 6];
+
+        assert_eq!(
+            rgb_to_rgba(&rgb, 2, 1, 6).unwrap(),
+            [1, 2, 3, 255, 4, 5, 6, 255]
+        );
+    }
+
+    #[test]
+    fn convert_general_path() {
+        let bgr = [3, 2, 1, 6, 5, 4];
+        assert_eq!(
+            convert(&bgr, 2, 1, 6, PixelFormat::Bgr, PixelFormat::Rgba).unwrap(),
+            [1, 2, 3, 255, 4, 5, 6, 255]
+        );
+
+        assert_eq!(
+            convert(&RGBA, 2, 1, 8, PixelFormat::Rgba, PixelFormat::Bgr).unwrap(),
+            [3, 2, 1, 7, 6, 5]
+        );
+    }
+
+    #[test]
+    fn convert_same_format_copies() {
+        assert_eq!(
+            convert(&RGBA, 1, 2, 4, PixelFormat::Rgba, PixelFormat::Rgba).unwrap(),
+            RGBA
+        );
+    }
+
+    #[test]
+    fn convert_skips_row_padding() {
+        // Two rows of one RGBA pixel, each padded to 6 bytes. The last row has no padding
+        let src = [1, 2, 3, 4, 0xAA, 0xAA, 5, 6, 7, 8];
+
+        assert_eq!(rgba_to_bgra(&src, 1, 2, 6).unwrap(), BGRA);
+    }
+
+    #[test]
+    fn round_trips() {
+        let bgra = rgba_to_bgra(&RGBA, 2, 1, 8).unwrap();
+        assert_eq!(bgra_to_rgba(&bgra, 2, 1, 8).unwrap(), RGBA);
+
+        let rgb = [1, 2, 3, 4, 5, 6];
+        let rgba = rgb_to_rgba(&rgb, 1, 2, 3).unwrap();
+        assert_eq!(
+            convert(&rgba, 1, 2, 4, PixelFormat::Rgba, PixelFormat::Rgb).unwrap(),
+            rgb
+        );
+    }
+
+    #[test]
+    fn short_buffer_is_rejected() {
+        assert!(matches!(
+            rgba_to_bgra(&RGBA[..7], 2, 1, 8),
+            Err(Error::UnexpectedValue { .. })
+        ));
+        // The padding after the first row counts towards the size
+        assert!(matches!(
+            rgba_to_bgra(&RGBA, 1, 2, 6),
+            Err(Error::UnexpectedValue { .. })
+        ));
+    }
+
+    #[test]
+    fn small_stride_is_rejected() {
+        assert!(matches!(
+            rgba_to_bgra(&RGBA, 2, 1, 4),
+            Err(Error::UnexpectedValue { .. })
+        ));
+    }
+
+    #[test]
+    fn float_formats_are_rejected() {
+        assert!(matches!(
+            convert(&RGBA, 2, 1, 8, PixelFormat::Rgba, PixelFormat::Rgba16F),
+            Err(Error::UnexpectedValue { .. })
+        ));
+    }
+
+    #[test]
+    fn empty_images() {
+        assert!(rgba_to_bgra(&[], 0, 4, 0).unwrap().is_empty());
+        assert!(rgba_to_bgra(&[], 4, 0, 16).unwrap().is_empty());
+    }
+
+    #[test]
+    fn check_layout_bounds() {
+        assert!(check_layout(10, 4, 2, 6).is_ok());
+        assert!(check_layout(9, 4, 2, 6).is_err());
+        assert!(check_layout(0, 4, 0, 4).is_ok());
+        assert!(check_layout(8, 4, 1, 3).is_err());
+    }
+}
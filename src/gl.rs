@@ -0,0 +1,98 @@
+//! The handful of OpenGL 1.1 functions needed outside of Spout, linked directly from
+//! `opengl32`. All of these require a current OpenGL context on the calling thread.
+//!
+//! On other platforms these are stubs. They are never reached, since every caller checks for a
+//! Spout handle first and there is never one off Windows.
+
+pub(crate) const GL_UNSIGNED_BYTE: u32 = 0x1401;
+pub(crate) const GL_FLOAT: u32 = 0x1406;
+pub(crate) const GL_HALF_FLOAT: u32 = 0x140B;
+pub(crate) const GL_MAX_TEXTURE_SIZE: u32 = 0x0D33;
+pub(crate) const GL_FRAMEBUFFER_BINDING: u32 = 0x8CA6;
+pub(crate) const GL_PACK_ALIGNMENT: u32 = 0x0D05;
+pub(crate) const GL_UNPACK_ALIGNMENT: u32 = 0x0CF5;
+pub(crate) const GL_TEXTURE_WIDTH: u32 = 0x1000;
+pub(crate) const GL_TEXTURE_HEIGHT: u32 = 0x1001;
+
+#[cfg(windows)]
+#[link(name = "opengl32")]
+extern "system" {
+    pub(crate) fn glBindTexture(target: u32, texture: u32);
+    pub(crate) fn glFlush();
+    pub(crate) fn glGetIntegerv(pname: u32, data: *mut i32);
+    pub(crate) fn glGetTexLevelParameteriv(target: u32, level: i32, pname: u32, params: *mut i32);
+    pub(crate) fn glGetTexImage(
+        target: u32,
+        level: i32,
+        format: u32,
+        type_: u32,
+        pixels: *mut std::ffi::c_void,
+    );
+    pub(crate) fn glPixelStorei(pname: u32, param: i32);
+    pub(crate) fn glTexSubImage2D(
+        target: u32,
+        level: i32,
+        xoffset: i32,
+        yoffset: i32,
+        width: i32,
+        height: i32,
+        format: u32,
+        type_: u32,
+        pixels: *const std::ffi::c_void,
+    );
+    pub(crate) fn wglGetCurrentContext() -> *mut std::ffi::c_void;
+}
+
+#[cfg(not(windows))]
+pub(crate) use self::stub::*;
+
+#[cfg(not(windows))]
+#[allow(non_snake_case)]
+mod stub {
+    pub(crate) unsafe fn glBindTexture(_target: u32, _texture: u32) {
+        unreachable!("OpenGL is not linked on this platform")
+    }
+    pub(crate) unsafe fn glFlush() {
+        unreachable!("OpenGL is not linked on this platform")
+    }
+    pub(crate) unsafe fn glGetIntegerv(_pname: u32, _data: *mut i32) {
+        unreachable!("OpenGL is not linked on this platform")
+    }
+    pub(crate) unsafe fn glGetTexLevelParameteriv(
+        _target: u32,
+        _level: i32,
+        _pname: u32,
+        _params: *mut i32,
+    ) {
+        unreachable!("OpenGL is not linked on this platform")
+    }
+    pub(crate) unsafe fn glGetTexImage(
+        _target: u32,
+        _level: i32,
+        _format: u32,
+        _type_: u32,
+        _pixels: *mut std::ffi::c_void,
+    ) {
+        unreachable!("OpenGL is not linked on this platform")
+    }
+    pub(crate) unsafe fn glPixelStorei(_pname: u32, _param: i32) {
+        unreachable!("OpenGL is not linked on this platform")
+    }
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) unsafe fn glTexSubImage2D(
+        _target: u32,
+        _level: i32,
+        _xoffset: i32,
+        _yoffset: i32,
+        _width: i32,
+        _height: i32,
+        _format: u32,
+        _type_: u32,
+        _pixels: *const std::ffi::c_void,
+    ) {
+        unreachable!("OpenGL is not linked on this platform")
+    }
+    pub(crate) unsafe fn wglGetCurrentContext() -> *mut std::ffi::c_void {
+        unreachable!("OpenGL is not linked on this platform")
+    }
+}
@@ -1,6 +1,10 @@
-use godot::{engine::global::Error, prelude::*};
+use autocxx::c_ulong;
+use godot::{
+    engine::{global::Error, image::Format, Image},
+    prelude::*,
+};
 
-use crate::RustySpout;
+use crate::{PixelFormat, RustySpout, SpoutStatus};
 
 struct SpoutGdExtension;
 
@@ -22,12 +26,19 @@ impl ObjectVirtual for SpoutGd {
 
 #[godot_api]
 impl SpoutGd {
+    /// Returns `ERR_CANT_CREATE` if Spout failed to create its library object, and
+    /// `ERR_UNAVAILABLE` if Spout is not supported on this platform.
     #[func]
     fn get_spout(&mut self) -> Error {
-        match self.library.get_spout() {
-            Ok(_) => Error::OK,
-            Err(e) => {
-                godot_error!("{e}");
+        let status = self.library.get_spout_status();
+        match status {
+            SpoutStatus::Loaded | SpoutStatus::AlreadyLoaded => Error::OK,
+            SpoutStatus::NullHandle => {
+                godot_error!("get_spout: {status:?}: Spout was unable to create its library");
+                Error::ERR_CANT_CREATE
+            }
+            SpoutStatus::UnsupportedPlatform => {
+                godot_error!("get_spout: {status:?}: Spout is only available on Windows");
                 Error::ERR_UNAVAILABLE
             }
         }
@@ -55,6 +66,100 @@ impl SpoutGd {
         }
     }
 
+    /// Returns `ERR_INVALID_PARAMETER` if the dimensions do not fit in a `u32`.
+    #[func]
+    fn create_sender(&mut self, name: GodotString, width: i64, height: i64) -> Error {
+        let Some((width, height)) = dimensions(width, height) else {
+            godot_error!("create_sender: invalid dimensions {width}x{height}");
+            return Error::ERR_INVALID_PARAMETER;
+        };
+
+        match self
+            .library
+            .create_sender(name.to_string(), width, height, c_ulong(0))
+        {
+            Ok(true) => Error::OK,
+            Ok(false) => {
+                godot_error!("create_sender: unable to create sender {name}");
+                Error::ERR_CANT_CREATE
+            }
+            Err(e) => error_code("create_sender", e),
+        }
+    }
+
+    /// Returns `ERR_INVALID_PARAMETER` if the dimensions do not fit in a `u32`.
+    #[func]
+    fn update_sender(&mut self, name: GodotString, width: i64, height: i64) -> Error {
+        let Some((width, height)) = dimensions(width, height) else {
+            godot_error!("update_sender: invalid dimensions {width}x{height}");
+            return Error::ERR_INVALID_PARAMETER;
+        };
+
+        match self.library.update_sender(name.to_string(), width, height) {
+            Ok(true) => Error::OK,
+            Ok(false) => {
+                godot_error!("update_sender: unable to update sender {name}");
+                Error::ERR_CANT_CREATE
+            }
+            Err(e) => error_code("update_sender", e),
+        }
+    }
+
+    /// Send the pixels of an `Image`.
+    ///
+    /// `FORMAT_RGBA8` and `FORMAT_RGB8` images are sent as they are. Any other format is
+    /// converted to `FORMAT_RGBA8` first, leaving `image` unchanged.
+    #[func]
+    fn send_image(&mut self, image: Gd<Image>, invert: bool) -> Error {
+        let (width, height) = (image.get_width(), image.get_height());
+
+        let (data, format) = match image.get_format() {
+            Format::FORMAT_RGBA8 => (image.get_data(), PixelFormat::Rgba),
+            Format::FORMAT_RGB8 => (image.get_data(), PixelFormat::Rgb),
+            format => {
+                let Some(mut converted) = Image::create_from_data(
+                    width,
+                    height,
+                    image.has_mipmaps(),
+                    format,
+                    image.get_data(),
+                ) else {
+                    godot_error!("send_image: unable to copy image with format {format:?}");
+                    return Error::ERR_INVALID_DATA;
+                };
+
+                converted.convert(Format::FORMAT_RGBA8);
+                if converted.get_format() != Format::FORMAT_RGBA8 {
+                    godot_error!("send_image: unable to convert format {format:?} to RGBA8");
+                    return Error::ERR_INVALID_DATA;
+                }
+
+                (converted.get_data(), PixelFormat::Rgba)
+            }
+        };
+
+        let Some((width, height)) = dimensions(width.into(), height.into()) else {
+            godot_error!("send_image: invalid dimensions {width}x{height}");
+            return Error::ERR_INVALID_PARAMETER;
+        };
+
+        // Mipmaps are stored after the full size image
+        let data = data.to_vec();
+        let size = format.buffer_size(width, height).min(data.len());
+
+        match self
+            .library
+            .send_image_slice(&data[..size], width, height, format, invert)
+        {
+            Ok(true) => Error::OK,
+            Ok(false) => {
+                godot_error!("send_image: unable to send image");
+                Error::FAILED
+            }
+            Err(e) => error_code("send_image", e),
+        }
+    }
+
     #[func]
     fn set_receiver_name(&mut self, sender_name: GodotString) -> Error {
         match self.library.set_receiver_name(sender_name.to_string()) {
@@ -66,6 +171,42 @@ impl SpoutGd {
         }
     }
 
+    /// Returns -1 on error.
+    #[func]
+    fn get_sender_width(&mut self) -> i64 {
+        match self.library.get_sender_width() {
+            Ok(v) => v.into(),
+            Err(e) => {
+                godot_error!("{e}");
+                -1
+            }
+        }
+    }
+
+    /// Returns -1 on error.
+    #[func]
+    fn get_sender_height(&mut self) -> i64 {
+        match self.library.get_sender_height() {
+            Ok(v) => v.into(),
+            Err(e) => {
+                godot_error!("{e}");
+                -1
+            }
+        }
+    }
+
+    /// Returns 0.0 on error.
+    #[func]
+    fn get_sender_fps(&mut self) -> f64 {
+        match self.library.get_sender_fps() {
+            Ok(v) => v,
+            Err(e) => {
+                godot_error!("{e}");
+                0.0
+            }
+        }
+    }
+
     #[func]
     fn read_memory_buffer(&mut self, buffer_name: GodotString, max_length: u32) -> Variant {
         let max_length = match usize::try_from(max_length) {
@@ -99,3 +240,23 @@ impl SpoutGd {
         }
     }
 }
+
+/// Convert dimensions from GDScript, which only has `i64`, into the `u32`s that Spout uses.
+fn dimensions(width: i64, height: i64) -> Option<(u32, u32)> {
+    Some((u32::try_from(width).ok()?, u32::try_from(height).ok()?))
+}
+
+/// Log an [Error](crate::Error) along with its variant and map it to the closest Godot error.
+fn error_code(context: &str, e: crate::Error) -> Error {
+    godot_error!("{context}: {e:?}: {e}");
+
+    match e {
+        crate::Error::NoHandle | crate::Error::Unbindable => Error::ERR_UNAVAILABLE,
+        crate::Error::FfiTypeInto { .. } => Error::ERR_INVALID_PARAMETER,
+        crate::Error::FfiTypeFrom { .. } | crate::Error::UnexpectedValue { .. } => {
+            Error::ERR_INVALID_DATA
+        }
+        crate::Error::NullPtr => Error::ERR_CANT_ACQUIRE_RESOURCE,
+        crate::Error::Io(_) => Error::ERR_CANT_OPEN,
+    }
+}
@@ -22,15 +22,34 @@ Each time the library is pinned for access, an unsafe block is used instead of s
 the unsafe block inside of a helper function.
 */
 
+pub mod convert;
+mod gl;
 #[cfg(feature = "godot")]
 mod godot;
+mod registry;
+mod roles;
+#[cfg(not(windows))]
+mod stub;
+
+pub use convert::flip_vertical;
+pub use roles::{FrameRateControl, Receiver, Sender};
 
 use std::{
-    ffi::{CStr, CString},
+    cell::{RefCell, RefMut},
+    collections::HashMap,
+    ffi::{c_char, CStr, CString},
+    path::{Path, PathBuf},
     pin::Pin,
+    rc::Rc,
+    time::{Duration, Instant},
 };
 
 use autocxx::prelude::*;
+#[cfg(feature = "windows")]
+use windows::{
+    core::Interface,
+    Win32::Graphics::Direct3D11::{ID3D11Device, ID3D11DeviceContext, ID3D11Texture2D},
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -46,6 +65,8 @@ pub enum Error {
     Unbindable,
     #[error("Unexpected value: {context:?}")]
     UnexpectedValue { context: String },
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
 }
 
 #[derive(Debug)]
@@ -56,6 +77,7 @@ pub enum FfiType {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DxgiGpuPreference {
     NotRegistered,
     Unspecified,
@@ -112,8 +134,266 @@ impl TryFrom<i32> for DxgiGpuPreference {
     }
 }
 
+/// DXGI texture formats that can be used for a shared sender texture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DxgiFormat {
+    /// `DXGI_FORMAT_R32G32B32A32_FLOAT`
+    R32G32B32A32Float,
+    /// `DXGI_FORMAT_R16G16B16A16_FLOAT`
+    R16G16B16A16Float,
+    /// `DXGI_FORMAT_R16G16B16A16_UNORM`
+    R16G16B16A16Unorm,
+    /// `DXGI_FORMAT_R10G10B10A2_UNORM`
+    R10G10B10A2Unorm,
+    /// `DXGI_FORMAT_R8G8B8A8_UNORM`
+    R8G8B8A8Unorm,
+    /// `DXGI_FORMAT_B8G8R8A8_UNORM`, the Spout default.
+    B8G8R8A8Unorm,
+    /// `DXGI_FORMAT_B8G8R8X8_UNORM`
+    B8G8R8X8Unorm,
+}
+
+impl From<DxgiFormat> for DWORD {
+    fn from(value: DxgiFormat) -> Self {
+        match value {
+            DxgiFormat::R32G32B32A32Float => c_ulong(2),
+            DxgiFormat::R16G16B16A16Float => c_ulong(10),
+            DxgiFormat::R16G16B16A16Unorm => c_ulong(11),
+            DxgiFormat::R10G10B10A2Unorm => c_ulong(24),
+            DxgiFormat::R8G8B8A8Unorm => c_ulong(28),
+            DxgiFormat::B8G8R8A8Unorm => c_ulong(87),
+            DxgiFormat::B8G8R8X8Unorm => c_ulong(88),
+        }
+    }
+}
+
+impl TryFrom<DWORD> for DxgiFormat {
+    type Error = Error;
+
+    fn try_from(value: DWORD) -> std::result::Result<Self, Self::Error> {
+        match value.0 {
+            2 => Ok(Self::R32G32B32A32Float),
+            10 => Ok(Self::R16G16B16A16Float),
+            11 => Ok(Self::R16G16B16A16Unorm),
+            24 => Ok(Self::R10G10B10A2Unorm),
+            28 => Ok(Self::R8G8B8A8Unorm),
+            87 => Ok(Self::B8G8R8A8Unorm),
+            88 => Ok(Self::B8G8R8X8Unorm),
+            _ => Err(Error::UnexpectedValue {
+                context: "DxgiFormat::try_from".to_string(),
+            }),
+        }
+    }
+}
+
+impl DxgiFormat {
+    /// The [PixelFormat] with the same channel layout, if there is one.
+    pub fn pixel_format(&self) -> Option<PixelFormat> {
+        match self {
+            DxgiFormat::R32G32B32A32Float => Some(PixelFormat::Rgba32F),
+            DxgiFormat::R16G16B16A16Float => Some(PixelFormat::Rgba16F),
+            DxgiFormat::R16G16B16A16Unorm | DxgiFormat::R10G10B10A2Unorm => None,
+            DxgiFormat::R8G8B8A8Unorm => Some(PixelFormat::Rgba),
+            DxgiFormat::B8G8R8A8Unorm | DxgiFormat::B8G8R8X8Unorm => Some(PixelFormat::Bgra),
+        }
+    }
+}
+
+/// Check if textures in two DXGI formats can be copied between without a conversion pass.
+///
+/// A format of 0 is treated as the Spout default, `DXGI_FORMAT_B8G8R8A8_UNORM`. The `X8`
+/// variant has the same layout, with the alpha channel ignored, so it is also interchangeable.
+/// The `_UNORM` and `_UNORM_SRGB` variants of a format only differ in how they are sampled, so
+/// they are interchangeable as well. A `_TYPELESS` format is compatible with every format of its
+/// family, but e.g. `DXGI_FORMAT_R16G16B16A16_FLOAT` and `_UNORM` are not compatible with each
+/// other.
+fn dxgi_formats_compatible(a: DWORD, b: DWORD) -> bool {
+    /// The `_TYPELESS` format of the family, and the format within it or `None` if typeless.
+    fn family(format: DWORD) -> (DWORD, Option<DWORD>) {
+        match format.0 {
+            1 => (c_ulong(1), None),
+            2 => (c_ulong(1), Some(format)),
+            9 => (c_ulong(9), None),
+            10 | 11 => (c_ulong(9), Some(format)),
+            23 => (c_ulong(23), None),
+            24 => (c_ulong(23), Some(format)),
+            27 => (c_ulong(27), None),
+            28 | 29 => (c_ulong(27), Some(c_ulong(28))),
+            90 | 92 => (c_ulong(90), None),
+            0 | 87 | 88 | 91 | 93 => (c_ulong(90), Some(c_ulong(87))),
+            _ => (format, Some(format)),
+        }
+    }
+
+    match (family(a), family(b)) {
+        ((a, Some(a_format)), (b, Some(b_format))) => a == b && a_format == b_format,
+        ((a, _), (b, _)) => a == b,
+    }
+}
+
+/// Check if the Spout tools are installed.
+///
+/// This checks that `SpoutPanel.exe` is registered under
+/// `HKEY_CURRENT_USER\Software\Leading Edge\SpoutPanel\InstallPath`, which is written when
+/// SpoutPanel or SpoutSettings is first run, and that the registered file exists. The Spout
+/// library itself is bundled with this crate and does not need to be installed.
+pub fn is_spout_installed() -> bool {
+    matches!(registry::spout_panel_path(), Ok(Some(_)))
+}
+
+/// The [DxgiFormat]s that Spout can share.
+pub fn supported_sender_formats() -> Vec<DxgiFormat> {
+    vec![
+        DxgiFormat::B8G8R8A8Unorm,
+        DxgiFormat::B8G8R8X8Unorm,
+        DxgiFormat::R8G8B8A8Unorm,
+        DxgiFormat::R10G10B10A2Unorm,
+        DxgiFormat::R16G16B16A16Unorm,
+        DxgiFormat::R16G16B16A16Float,
+        DxgiFormat::R32G32B32A32Float,
+    ]
+}
+
+/// The OpenGL formats that Spout can receive image pixels into, as passed to
+/// [RustySpout::receive_image]. Each is the [PixelFormat::gl_format] of an 8-bit [PixelFormat].
+pub fn supported_gl_formats() -> &'static [GLenum] {
+    &[GL_RGBA, GL_BGRA_EXT, GL_RGB, GL_BGR_EXT]
+}
+
+/// Check that `name` can be used as a sender name.
+///
+/// Spout names the shared memory and texture handles of a sender after it, so a valid name:
+/// * is not empty, since an empty name means the active sender or the executable name
+/// * is shorter than [MAX_SENDER_NAME_LEN] bytes, leaving room for the nul terminator
+/// * has no nul, backslash, or control characters
+///
+/// Returns an [Error::UnexpectedValue] describing the first problem found.
+pub fn validate_sender_name(name: &str) -> Result<()> {
+    let invalid = |reason: String| {
+        Err(Error::UnexpectedValue {
+            context: format!("validate_sender_name: {name:?} {reason}"),
+        })
+    };
+
+    if name.is_empty() {
+        return invalid("is empty".to_string());
+    }
+    if name.len() >= MAX_SENDER_NAME_LEN {
+        return invalid(format!(
+            "is {} bytes, the limit is {}",
+            name.len(),
+            MAX_SENDER_NAME_LEN - 1
+        ));
+    }
+    if let Some(c) = name.chars().find(|c| *c == '\\' || c.is_control()) {
+        return invalid(format!("contains the invalid character {c:?}"));
+    }
+
+    Ok(())
+}
+
+/// The path of the current executable as a string, for the Spout functions that take one.
+fn current_exe_path() -> Result<String> {
+    let path = std::env::current_exe()?;
+
+    match path.into_os_string().into_string() {
+        Ok(v) => Ok(v),
+        Err(path) => Err(Error::UnexpectedValue {
+            context: format!("current_exe_path: {path:?} is not valid UTF-8"),
+        }),
+    }
+}
+
+/// How textures are shared between processes. Maps to the `int` used by `GetShareMode`
+/// and `SetShareMode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ShareMode {
+    Texture,
+    Memory,
+    Cpu,
+}
+
+impl From<ShareMode> for i32 {
+    fn from(value: ShareMode) -> Self {
+        match value {
+            ShareMode::Texture => 0,
+            ShareMode::Memory => 1,
+            ShareMode::Cpu => 2,
+        }
+    }
+}
+
+impl TryFrom<i32> for ShareMode {
+    type Error = Error;
+
+    fn try_from(value: i32) -> std::result::Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Texture),
+            1 => Ok(Self::Memory),
+            2 => Ok(Self::Cpu),
+            _ => Err(Error::UnexpectedValue {
+                context: "ShareMode::try_from".to_string(),
+            }),
+        }
+    }
+}
+
+/// The path that frames take between processes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SharingPath {
+    /// Share GPU textures, automatically falling back to CPU sharing if GL/DX interop is not
+    /// available.
+    Auto,
+    /// Always share GPU textures through GL/DX interop.
+    GpuTexture,
+    /// Share frames through a shared memory map. Only for compatibility with 2.006 applications.
+    GpuMemory,
+    /// Copy frames through the CPU using DirectX textures.
+    Cpu,
+}
+
+/// Spout settings that apply to every sender and receiver on the system.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlobalSettings {
+    pub buffer_mode: bool,
+    pub buffers: i32,
+    pub max_senders: i32,
+    pub share_mode: ShareMode,
+    pub auto_share: bool,
+    pub frame_count: bool,
+}
+
 type Result<T> = std::result::Result<T, Error>;
 
+/// The maximum length of a sender name, including the nul terminator.
+pub const MAX_SENDER_NAME_LEN: usize = 256;
+
+/// The Spout release this crate was built against, e.g. `2.007.011`.
+///
+/// Set from `SPOUT_TAG` at build time. With the `prebuilt` feature, set `SPOUT_TAG` to the
+/// version of the prebuilt library for this to be accurate.
+pub const SPOUT_BUILT_VERSION: &str = env!("SPOUT_BUILT_VERSION");
+
+/// The smallest value accepted by [RustySpout::set_buffers].
+pub const MIN_BUFFERS: i32 = 1;
+/// The largest value accepted by [RustySpout::set_buffers].
+pub const MAX_BUFFERS: i32 = 4;
+
+/// The smallest value accepted by [RustySpout::set_max_senders].
+pub const MIN_MAX_SENDERS: i32 = 1;
+/// The largest value accepted by [RustySpout::set_max_senders]. Each sender takes
+/// [MAX_SENDER_NAME_LEN] bytes in the shared sender name map, so this keeps the map at 256KiB.
+pub const MAX_MAX_SENDERS: i32 = 1024;
+
+/// `D3D11_REQ_TEXTURE2D_U_OR_V_DIMENSION`, the largest texture dimension DirectX 11 guarantees.
+pub const DX11_MAX_TEXTURE_SIZE: u32 = 16384;
+
+/// How many names are kept by the [CString] cache before the least recently used is dropped.
+const CSTRING_CACHE_CAPACITY: usize = 16;
+
 // Typedefs using concrete types instead of ffi types for readability.
 
 /// A Windows DWORD which _should_ be a ulong.
@@ -128,6 +408,338 @@ pub type GLenum = c_uint;
 /// Enum representing log levels.
 pub type SpoutLibLogLevel = ffi::SpoutLibLogLevel;
 
+/// Information about a named sender, as reported by `GetSenderInfo`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SenderInfo {
+    pub width: u32,
+    pub height: u32,
+    pub share_handle: HANDLE,
+    pub format: DWORD,
+}
+
+/// Changes to the sender list, as returned by [RustySpout::sender_diff].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SenderDiff {
+    /// Senders that appeared since the last call.
+    pub added: Vec<String>,
+    /// Senders that closed since the last call.
+    pub removed: Vec<String>,
+}
+
+impl SenderDiff {
+    /// Whether the sender list is unchanged.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Everything needed to use the shared texture of the connected sender from another graphics
+/// library, as returned by [RustySpout::shared_texture_descriptor].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SharedTextureDescriptor {
+    /// The OpenGL ID of the shared texture.
+    pub texture_id: GLuint,
+    /// The OpenGL target the texture is bound to, always [GL_TEXTURE_2D].
+    pub target: GLenum,
+    pub width: u32,
+    pub height: u32,
+    /// The DXGI format of the sender's texture.
+    pub format: DWORD,
+}
+
+/// How the Spout found at runtime compares to [SPOUT_BUILT_VERSION], as returned by
+/// [RustySpout::check_version_compatibility].
+///
+/// `sdk_version` is the version of the loaded `SpoutLibrary.dll` and `spout_version` is the
+/// version registered by SpoutSettings, e.g. `2007` for 2.007, or 0 if it is not installed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VersionCompat {
+    /// Both match the version the crate was built against.
+    Compatible {
+        sdk_version: String,
+        spout_version: i32,
+    },
+    /// The loaded library or the installed Spout is older.
+    Older {
+        sdk_version: String,
+        spout_version: i32,
+    },
+    /// The loaded library or the installed Spout is newer.
+    Newer {
+        sdk_version: String,
+        spout_version: i32,
+    },
+}
+
+/// Version information for this crate and Spout, as returned by [RustySpout::versions].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Versions {
+    /// The version of this crate.
+    pub crate_version: &'static str,
+    /// The version registered by SpoutSettings, e.g. `2007` for 2.007, or 0 if it is not installed.
+    pub spout_version: i32,
+    /// The version of the loaded `SpoutLibrary.dll`.
+    pub sdk_version: String,
+}
+
+/// Parse a `major.minor.patch` version like `2.007.011`, optionally with a leading `v`. A missing
+/// patch is 0.
+fn parse_spout_version(version: &str) -> Option<(u32, u32, u32)> {
+    let version = version.trim();
+    let version = version.strip_prefix(['v', 'V']).unwrap_or(version);
+    let mut parts = version.split('.').map(|v| v.parse::<u32>().ok());
+
+    let major = parts.next()??;
+    let minor = parts.next()??;
+    let patch = parts.next().unwrap_or(Some(0))?;
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some((major, minor, patch))
+}
+
+/// Compare a parsed library version and the registered Spout version against the parsed built
+/// version, as described in [RustySpout::check_version_compatibility].
+fn compare_spout_versions(
+    built: (u32, u32, u32),
+    sdk: (u32, u32, u32),
+    spout_version: i32,
+) -> std::cmp::Ordering {
+    match sdk.cmp(&built) {
+        std::cmp::Ordering::Equal if spout_version > 0 => {
+            // Registered as e.g. 2007, but allow 2007011 as well
+            let installed = match spout_version as u32 {
+                v if v >= 1_000_000 => (v / 1_000_000, v / 1_000 % 1_000),
+                v => (v / 1_000, v % 1_000),
+            };
+            installed.cmp(&(built.0, built.1))
+        }
+        v => v,
+    }
+}
+
+/// A graphics adapter as enumerated by Spout, for matching adapters by name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct AdapterInfo {
+    index: i32,
+    name: String,
+}
+
+/// Details about a graphics adapter, as returned by [RustySpout::adapter_details].
+///
+/// Everything except the name comes from DXGI and requires the `windows` feature.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AdapterDetails {
+    pub index: i32,
+    /// The name as reported by Spout.
+    pub name: String,
+    /// The description as reported by DXGI. Usually the same as the name.
+    #[cfg(feature = "windows")]
+    pub description: String,
+    /// Video memory in bytes that is not shared with the CPU.
+    #[cfg(feature = "windows")]
+    pub dedicated_video_memory: u64,
+    /// The PCI vendor ID, e.g. `0x10DE` for NVIDIA.
+    #[cfg(feature = "windows")]
+    pub vendor_id: u32,
+}
+
+/// The adapters of a laptop with switchable graphics.
+///
+/// Either index may be `None` if Windows does not report a preferred adapter for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LaptopInfo {
+    /// Index of the power saving, usually integrated, adapter.
+    pub integrated: Option<i32>,
+    /// Index of the high performance, usually discrete, adapter.
+    pub discrete: Option<i32>,
+}
+
+/// OpenGL `GL_TEXTURE_2D`.
+pub const GL_TEXTURE_2D: GLenum = c_uint(0x0DE1);
+/// OpenGL `GL_RGB`.
+pub const GL_RGB: GLenum = c_uint(0x1907);
+/// OpenGL `GL_RGBA`.
+pub const GL_RGBA: GLenum = c_uint(0x1908);
+/// OpenGL `GL_BGR_EXT`.
+pub const GL_BGR_EXT: GLenum = c_uint(0x80E0);
+/// OpenGL `GL_BGRA_EXT`.
+pub const GL_BGRA_EXT: GLenum = c_uint(0x80E1);
+
+/// Pixel layouts accepted by the image send and receive functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PixelFormat {
+    Rgba,
+    Bgra,
+    Rgb,
+    Bgr,
+    /// RGBA with a 16-bit half float per channel.
+    Rgba16F,
+    /// RGBA with a 32-bit float per channel.
+    Rgba32F,
+}
+
+impl PixelFormat {
+    /// The matching OpenGL format to pass to Spout.
+    pub fn gl_format(&self) -> GLenum {
+        match self {
+            PixelFormat::Rgba | PixelFormat::Rgba16F | PixelFormat::Rgba32F => GL_RGBA,
+            PixelFormat::Bgra => GL_BGRA_EXT,
+            PixelFormat::Rgb => GL_RGB,
+            PixelFormat::Bgr => GL_BGR_EXT,
+        }
+    }
+
+    /// The number of bytes used by a single pixel.
+    pub fn bytes_per_pixel(&self) -> usize {
+        match self {
+            PixelFormat::Rgba | PixelFormat::Bgra => 4,
+            PixelFormat::Rgb | PixelFormat::Bgr => 3,
+            PixelFormat::Rgba16F => 8,
+            PixelFormat::Rgba32F => 16,
+        }
+    }
+
+    /// Whether the channels are floating point.
+    ///
+    /// Spout's image functions only handle 8-bit channels, so float pixels must be shared as a
+    /// texture from a sender created with the matching [dxgi_format](Self::dxgi_format).
+    pub fn is_float(&self) -> bool {
+        matches!(self, PixelFormat::Rgba16F | PixelFormat::Rgba32F)
+    }
+
+    /// The [DxgiFormat] of a shared texture with the same layout, if there is one.
+    pub fn dxgi_format(&self) -> Option<DxgiFormat> {
+        match self {
+            PixelFormat::Rgba => Some(DxgiFormat::R8G8B8A8Unorm),
+            PixelFormat::Bgra => Some(DxgiFormat::B8G8R8A8Unorm),
+            PixelFormat::Rgb | PixelFormat::Bgr => None,
+            PixelFormat::Rgba16F => Some(DxgiFormat::R16G16B16A16Float),
+            PixelFormat::Rgba32F => Some(DxgiFormat::R32G32B32A32Float),
+        }
+    }
+
+    /// The OpenGL type of a single channel.
+    fn gl_type(&self) -> u32 {
+        match self {
+            PixelFormat::Rgba16F => gl::GL_HALF_FLOAT,
+            PixelFormat::Rgba32F => gl::GL_FLOAT,
+            _ => gl::GL_UNSIGNED_BYTE,
+        }
+    }
+
+    /// Return an [Error::UnexpectedValue] for float formats, which Spout's image functions
+    /// cannot handle.
+    fn ensure_8_bit(&self, context: &str) -> Result<()> {
+        if self.is_float() {
+            return Err(Error::UnexpectedValue {
+                context: format!(
+                    "{context}: {self:?} is only supported when sharing textures, not images"
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// The number of bytes needed for an image of the given dimensions.
+    pub fn buffer_size(&self, width: u32, height: u32) -> usize {
+        width as usize * height as usize * self.bytes_per_pixel()
+    }
+
+    /// The byte offsets of the red, green, blue, and (if present) alpha channels in a pixel.
+    ///
+    /// Only meaningful for 8-bit formats.
+    fn channel_offsets(&self) -> (usize, usize, usize, Option<usize>) {
+        match self {
+            PixelFormat::Rgba | PixelFormat::Rgba16F | PixelFormat::Rgba32F => (0, 1, 2, Some(3)),
+            PixelFormat::Bgra => (2, 1, 0, Some(3)),
+            PixelFormat::Rgb => (0, 1, 2, None),
+            PixelFormat::Bgr => (2, 1, 0, None),
+        }
+    }
+
+    /// The OpenGL format that reads or writes the bytes of this format without reordering.
+    fn unswizzled_gl_format(&self) -> GLenum {
+        match self {
+            PixelFormat::Rgba | PixelFormat::Bgra | PixelFormat::Rgba16F | PixelFormat::Rgba32F => {
+                GL_RGBA
+            }
+            PixelFormat::Rgb | PixelFormat::Bgr => GL_RGB,
+        }
+    }
+}
+
+/// Generated images for checking that a receiver is set up correctly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TestPattern {
+    /// Every pixel is the given RGBA color.
+    SolidColor([u8; 4]),
+    /// An 8x8 grid of alternating black and white squares.
+    Checkerboard,
+    /// Vertical bars of white, yellow, cyan, green, magenta, red, and blue.
+    ColorBars,
+}
+
+impl TestPattern {
+    /// Generate the pattern as [PixelFormat::Rgba] pixels.
+    pub fn pixels(&self, width: u32, height: u32) -> Vec<u8> {
+        const BARS: [[u8; 4]; 7] = [
+            [255, 255, 255, 255],
+            [255, 255, 0, 255],
+            [0, 255, 255, 255],
+            [0, 255, 0, 255],
+            [255, 0, 255, 255],
+            [255, 0, 0, 255],
+            [0, 0, 255, 255],
+        ];
+
+        let mut pixels = Vec::with_capacity(PixelFormat::Rgba.buffer_size(width, height));
+
+        let square_width = (width / 8).max(1);
+        let square_height = (height / 8).max(1);
+        let bar_width = (width / BARS.len() as u32).max(1);
+
+        for y in 0..height {
+            for x in 0..width {
+                let color = match self {
+                    TestPattern::SolidColor(color) => *color,
+                    TestPattern::Checkerboard => {
+                        if (x / square_width + y / square_height) % 2 == 0 {
+                            [255, 255, 255, 255]
+                        } else {
+                            [0, 0, 0, 255]
+                        }
+                    }
+                    TestPattern::ColorBars => BARS[((x / bar_width) as usize).min(BARS.len() - 1)],
+                };
+                pixels.extend_from_slice(&color);
+            }
+        }
+
+        pixels
+    }
+}
+
+/// Check if received pixels are all zero, as Spout produces while a sender is switching.
+///
+/// Only an evenly spaced sample of up to 4096 bytes and the last byte are checked, which is enough
+/// to catch the cleared frames seen during transitions without scanning the whole buffer. A black
+/// frame with an opaque alpha channel is not blank. An empty buffer is blank.
+pub fn is_frame_blank(pixels: &[u8]) -> bool {
+    const SAMPLES: usize = 4096;
+
+    let step = pixels.len().div_ceil(SAMPLES).max(1);
+
+    pixels.iter().step_by(step).all(|v| *v == 0) && pixels.last().map_or(true, |v| *v == 0)
+}
+
+// Spout is Windows-only, other platforms get a stub that never returns a handle
+#[cfg(not(windows))]
+use stub as ffi;
+
+#[cfg(windows)]
 include_cpp! {
     #include "SpoutLibrary.h"
 
@@ -137,6 +749,27 @@ include_cpp! {
     generate!("SPOUTLIBRARY")
 }
 
+/// Raw autocxx bindings to `SpoutLibrary`, for calling functions that are not wrapped yet.
+///
+/// # Unstable
+/// **Everything in this module is unsafe to use and may change in any release.** The contents
+/// are generated by autocxx and follow the Spout SDK, not this crate's API.
+#[cfg(feature = "unstable-ffi")]
+pub mod unstable_ffi {
+    use std::pin::Pin;
+
+    pub use crate::ffi::*;
+
+    /// Pin a `SPOUTLIBRARY` pointer so its methods can be called.
+    ///
+    /// # Safety
+    /// The `ptr` must be a valid `SPOUTLIBRARY` pointer, and must not be released while the
+    /// returned reference is alive.
+    pub unsafe fn as_pin<'a>(ptr: *mut SPOUTLIBRARY) -> Pin<&'a mut SPOUTLIBRARY> {
+        crate::as_pin(ptr)
+    }
+}
+
 /// Helper for getting a usable library handle.
 macro_rules! library {
     ($lib:expr) => {{
@@ -179,15 +812,16 @@ macro_rules! cstring_to_string {
     }};
 }
 
-/// Conversion helper for creating a [CStr] from a buffer.
-macro_rules! buf_to_cstr {
-    ($buf:expr) => {{
-        match CStr::from_bytes_with_nul($buf.as_slice()) {
-            Ok(v) => v,
+/// Conversion helper for creating [String]s from a buffer that Spout wrote a nul terminated
+/// string into. Anything after the first nul is ignored.
+macro_rules! buf_to_string {
+    ($fn_name:expr, $buf:expr) => {{
+        match CStr::from_bytes_until_nul($buf.as_slice()) {
+            Ok(v) => cstring_to_string!($fn_name, v),
             Err(e) => {
-                return Err(Error::FfiTypeInto {
+                return Err(Error::FfiTypeFrom {
                     ffi_type: FfiType::CStr,
-                    context: format!("{} - {}: {e}", file!(), line!()),
+                    context: format!("{}: {e}", $fn_name),
                 })
             }
         }
@@ -208,9 +842,309 @@ macro_rules! usize_to_c_int {
     }};
 }
 
+/// Read a nul terminated string that Spout returned a pointer to, looking at no more than `max`
+/// bytes.
+///
+/// Unlike [CStr::from_ptr], a missing nul terminator is an [Error::FfiTypeFrom] instead of a read
+/// past the end of the string.
+///
+/// # Safety
+/// `ptr` must be `nullptr` or valid for reads up to its nul terminator or `max` bytes, whichever
+/// comes first.
+unsafe fn read_bounded_cstr(ptr: *const c_char, max: usize) -> Result<String> {
+    if ptr.is_null() {
+        return Err(Error::NullPtr);
+    }
+
+    let Some(len) = (0..max).find(|i| unsafe { *ptr.add(*i) } == 0) else {
+        return Err(Error::FfiTypeFrom {
+            ffi_type: FfiType::CStr,
+            context: format!("read_bounded_cstr: no nul terminator within {max} bytes"),
+        });
+    };
+
+    let bytes = unsafe { std::slice::from_raw_parts(ptr.cast::<u8>(), len) };
+    match std::str::from_utf8(bytes) {
+        Ok(v) => Ok(v.to_string()),
+        Err(e) => Err(Error::FfiTypeFrom {
+            ffi_type: FfiType::CStr,
+            context: format!("read_bounded_cstr: {e}"),
+        }),
+    }
+}
+
+/// Convert a sender name for the receiver methods. An empty name is `None`, which
+/// [name_ptr] turns into the `nullptr` that Spout treats as the active sender.
+fn receiver_name(context: &str, name: &str) -> Result<Option<CString>> {
+    if name.is_empty() {
+        return Ok(None);
+    }
+
+    match CString::new(name) {
+        Ok(v) => Ok(Some(v)),
+        Err(e) => Err(Error::FfiTypeInto {
+            ffi_type: FfiType::CString,
+            context: format!("{context}: {e}"),
+        }),
+    }
+}
+
+/// The pointer to pass to Spout for a name from [receiver_name], `nullptr` for `None`.
+fn name_ptr(name: &Option<CString>) -> *const c_char {
+    match name {
+        Some(v) => v.as_ptr(),
+        None => std::ptr::null(),
+    }
+}
+
+/// Extends Spout's 32-bit frame numbers into a monotonic 64-bit count.
+#[derive(Debug, Default)]
+struct FrameCounter {
+    last: Option<u32>,
+    total: u64,
+}
+
+impl FrameCounter {
+    /// Record a raw frame number and return the extended count.
+    ///
+    /// The raw value is treated as unsigned, so overflowing past `i32::MAX` keeps counting up.
+    /// A small backwards step is a 32-bit wraparound. A large backwards step means the counter
+    /// was reset, so counting continues from the current total.
+    fn update(&mut self, raw: i32) -> u64 {
+        let raw = raw as u32;
+
+        match self.last {
+            Some(last) => {
+                let delta = raw.wrapping_sub(last);
+                if delta <= i32::MAX as u32 {
+                    self.total += delta as u64;
+                } else {
+                    self.total += raw as u64;
+                }
+            }
+            None => self.total = raw as u64,
+        }
+        self.last = Some(raw);
+
+        self.total
+    }
+}
+
+/// Parse a line of the Spout log into a [log::Level] and message.
+///
+/// Spout prefixes each entry with its level in brackets, e.g. `[warning] `. Lines without a
+/// known prefix are treated as [log::Level::Info].
+#[cfg(feature = "log")]
+fn parse_spout_log_line(line: &str) -> (log::Level, &str) {
+    let line = line.trim_end();
+
+    if let Some((prefix, message)) = line.strip_prefix('[').and_then(|rest| rest.split_once(']')) {
+        let level = match prefix.trim().to_ascii_lowercase().as_str() {
+            "verbose" => Some(log::Level::Debug),
+            "notice" => Some(log::Level::Info),
+            "warning" => Some(log::Level::Warn),
+            "error" | "fatal" => Some(log::Level::Error),
+            _ => None,
+        };
+        if let Some(level) = level {
+            return (level, message.trim_start());
+        }
+    }
+
+    (log::Level::Info, line)
+}
+
+/// The non-empty lines of `spout_log` completed since `cursor`, advancing `cursor` past them.
+///
+/// A trailing line without a newline may still be being written, so it is left for the next call.
+/// If `cursor` is past the end, the log file was truncated or recreated and is read from the start.
+#[cfg(feature = "log")]
+fn new_spout_log_lines<'a>(
+    spout_log: &'a str,
+    cursor: &mut usize,
+) -> impl Iterator<Item = &'a str> {
+    if *cursor > spout_log.len() || !spout_log.is_char_boundary(*cursor) {
+        *cursor = 0;
+    }
+
+    let pending = &spout_log[*cursor..];
+    let complete = match pending.rfind('\n') {
+        Some(v) => &pending[..=v],
+        None => "",
+    };
+    *cursor += complete.len();
+
+    complete.lines().filter(|v| !v.trim().is_empty())
+}
+
+/// Frame timing for a sender.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FpsReport {
+    /// The frame rate measured by Spout, from [RustySpout::get_fps].
+    pub achieved: f64,
+    /// The last value passed to [RustySpout::hold_fps], or `None` if the rate is not held.
+    pub requested: Option<i32>,
+    /// The system refresh rate, from [RustySpout::get_refresh_rate].
+    pub refresh_rate: f64,
+}
+
+/// The outcome of [RustySpout::receive_image_into].
+///
+/// Spout clears its updated flag when it is read, so this is the only place a receive reports
+/// that the sender changed. Calling [RustySpout::is_updated] afterwards always returns `false`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReceiveStatus {
+    /// The pixels of the sender were copied into the buffer.
+    Received,
+    /// Connected to a sender or the sender changed. The buffer was resized to fit the sender
+    /// instead of being filled, so receive again for pixels.
+    Updated,
+    /// There is no sender to receive from.
+    NotConnected,
+}
+
+impl ReceiveStatus {
+    /// Whether pixels were copied into the buffer.
+    pub fn is_received(&self) -> bool {
+        matches!(self, ReceiveStatus::Received)
+    }
+
+    /// Whether there is a sender, i.e. the status is not [ReceiveStatus::NotConnected].
+    pub fn is_connected(&self) -> bool {
+        !matches!(self, ReceiveStatus::NotConnected)
+    }
+}
+
+/// Check that a receive produced a frame that has not been returned yet, updating `last`.
+///
+/// `frame` is the sender frame number if Spout reported a new frame. Frame counting may be
+/// disabled, in which case the frame number is always 0 and every new frame is accepted.
+fn accept_frame(status: ReceiveStatus, frame: Option<i32>, last: &mut Option<i32>) -> bool {
+    let Some(frame) = frame.filter(|_| status.is_received()) else {
+        return false;
+    };
+    if frame != 0 && *last == Some(frame) {
+        return false;
+    }
+    *last = Some(frame);
+
+    true
+}
+
+/// A frame received with [RustySpout::receive_next_frame].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FrameData {
+    pub pixels: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+    pub format: PixelFormat,
+    /// The sender frame number, or 0 if frame counting is disabled.
+    pub frame: i32,
+}
+
+/// A frame received with [RustySpout::recv], borrowing its internal buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Frame<'a> {
+    pixels: &'a [u8],
+    width: u32,
+    height: u32,
+    format: PixelFormat,
+}
+
+impl Frame<'_> {
+    /// The width of the frame in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// The height of the frame in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The layout of [pixels](Self::pixels).
+    pub fn format(&self) -> PixelFormat {
+        self.format
+    }
+
+    /// The received pixels, valid until the next call to [RustySpout::recv].
+    pub fn pixels(&self) -> &[u8] {
+        self.pixels
+    }
+}
+
+/// The outcome of [RustySpout::get_spout_status].
+///
+/// `SpoutLibrary.dll` is linked when the process starts, so a missing DLL stops the process with a
+/// Windows error (`STATUS_DLL_NOT_FOUND`) before any of this can run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpoutStatus {
+    /// A new handle was created.
+    Loaded,
+    /// There already was a handle, which was kept.
+    AlreadyLoaded,
+    /// The DLL is loaded but `GetSpout` returned a `nullptr`, so Spout was unable to create its
+    /// library object.
+    NullHandle,
+    /// Spout is Windows-only and this build uses a stub without a library.
+    UnsupportedPlatform,
+}
+
+/// Whether a [RustySpout] has last been used to send or to receive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    Sender,
+    Receiver,
+}
+
 /// Wrapper around `SPOUTLIBRARY`.
 pub struct RustySpout {
     library: Option<*mut ffi::SPOUTLIBRARY>,
+    /// The sender frame number of the last frame returned from `receive_image_if_new`.
+    last_received_frame: Option<i32>,
+    frame_counter: FrameCounter,
+    sender_frame_counter: FrameCounter,
+    /// The `invert` value used by the `_auto` send and receive methods.
+    default_invert: bool,
+    /// The last value passed to `hold_fps`.
+    requested_fps: Option<i32>,
+    /// Skip the readiness guards in `ensure_ready`.
+    assume_ready: bool,
+    /// Check that the FBO passed to `send_fbo` is bound.
+    validate_fbo: bool,
+    /// The file name last passed to `enable_spout_log_file`.
+    log_file: Option<PathBuf>,
+    /// The level last passed to `set_spout_log_level`, restored by `suppress_message_boxes`.
+    log_level: Option<SpoutLibLogLevel>,
+    /// Whether `suppress_message_boxes` has disabled logging.
+    message_boxes_suppressed: bool,
+    /// Whether this is currently sending or receiving, for the [Debug] impl.
+    role: Option<Role>,
+    /// The sender name as set by `set_sender_name` or `create_sender`.
+    sender_name: Option<String>,
+    /// Pixel buffer reused by `recv`.
+    recv_buffer: Vec<u8>,
+    /// How long `get_sender_info` results are cached for, or `None` to not cache.
+    sender_info_cache_ttl: Option<Duration>,
+    sender_info_cache: HashMap<String, (Instant, Option<SenderInfo>)>,
+    /// The sender that `relay` is receiving from.
+    relay_source: Option<String>,
+    /// The sender name passed to `relay` and the second instance it sends through.
+    relay_sender: Option<(String, Box<RustySpout>)>,
+    /// The sender list as of the last call to `sender_diff`.
+    known_senders: Vec<String>,
+    /// The sender size last reported by `texture_recreate_needed`.
+    receive_texture_size: Option<(u32, u32)>,
+    /// The name of the buffer created by `create_memory_buffer`, for `resize_memory_buffer`.
+    memory_buffer_name: Option<String>,
+    /// Names converted by `cached_cstring`, least recently used first.
+    cstring_cache: Vec<(String, CString)>,
+    /// CPU staging buffer for `receive_into_wgpu_buffer`.
+    #[cfg(feature = "wgpu")]
+    wgpu_staging: Vec<u8>,
+    /// Byte offset into the Spout log of the next entry to forward to the `log` crate.
+    #[cfg(feature = "log")]
+    log_cursor: usize,
 }
 
 impl Drop for RustySpout {
@@ -223,33 +1157,255 @@ impl Drop for RustySpout {
     }
 }
 
+/// Only prints state cached on the Rust side, Spout is never called.
+impl std::fmt::Debug for RustySpout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RustySpout")
+            .field("has_library", &self.library.is_some())
+            .field("role", &self.role)
+            .field("sender_name", &self.sender_name)
+            .field("default_invert", &self.default_invert)
+            .field("requested_fps", &self.requested_fps)
+            .field("assume_ready", &self.assume_ready)
+            .field("validate_fbo", &self.validate_fbo)
+            .finish_non_exhaustive()
+    }
+}
+
 impl RustySpout {
     /// Create a new, uninitialized handler.
     pub fn new() -> Self {
-        Self { library: None }
+        Self {
+            library: None,
+            last_received_frame: None,
+            frame_counter: FrameCounter::default(),
+            sender_frame_counter: FrameCounter::default(),
+            default_invert: false,
+            requested_fps: None,
+            assume_ready: false,
+            validate_fbo: false,
+            log_file: None,
+            log_level: None,
+            message_boxes_suppressed: false,
+            role: None,
+            sender_name: None,
+            recv_buffer: Vec::new(),
+            sender_info_cache_ttl: None,
+            sender_info_cache: HashMap::new(),
+            relay_source: None,
+            relay_sender: None,
+            known_senders: Vec::new(),
+            receive_texture_size: None,
+            memory_buffer_name: None,
+            cstring_cache: Vec::new(),
+            #[cfg(feature = "wgpu")]
+            wgpu_staging: Vec::new(),
+            #[cfg(feature = "log")]
+            log_cursor: 0,
+        }
     }
 
     /// Get a handle to spout.
+    ///
+    /// Does nothing if there already is a handle. An [Error::NoHandle] is returned if no handle
+    /// could be created, use [get_spout_status](Self::get_spout_status) to find out why.
     pub fn get_spout(&mut self) -> Result<()> {
+        match self.get_spout_status() {
+            SpoutStatus::Loaded | SpoutStatus::AlreadyLoaded => Ok(()),
+            SpoutStatus::NullHandle | SpoutStatus::UnsupportedPlatform => Err(Error::NoHandle),
+        }
+    }
+
+    /// Get a handle to spout, reporting why it failed.
+    pub fn get_spout_status(&mut self) -> SpoutStatus {
+        if self.library.is_some() {
+            return SpoutStatus::AlreadyLoaded;
+        }
+        if cfg!(not(windows)) {
+            return SpoutStatus::UnsupportedPlatform;
+        }
+
         let handle = ffi::GetSpout();
         if handle.is_null() {
-            return Err(Error::NoHandle);
+            return SpoutStatus::NullHandle;
         }
 
         self.library = Some(handle);
 
-        Ok(())
+        SpoutStatus::Loaded
     }
 
-    /// Set the sender name.
+    /// Get the raw `SPOUTLIBRARY` pointer, or `None` if there is no handle.
     ///
     /// # Safety
-    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
-    pub fn set_sender_name<T: AsRef<str>>(&mut self, name: T) -> Result<()> {
-        let lib = unsafe { library!(self.library) };
+    /// The pointer is owned by this struct and is released when it is dropped or when
+    /// [release](Self::release) is called. It must not be used after that.
+    #[cfg(feature = "unstable-ffi")]
+    pub fn as_raw_ptr(&self) -> Option<*mut ffi::SPOUTLIBRARY> {
+        self.library
+    }
 
-        let name = match CString::new(name.as_ref()) {
-            Ok(v) => v,
+    /// Get the pinned `SPOUTLIBRARY` for calling methods that are not wrapped by this crate.
+    ///
+    /// # Safety
+    /// The returned reference borrows this struct, so the library cannot be released while it is
+    /// alive. The caller must additionally uphold:
+    /// * `Release` must not be called through the returned reference, since this struct releases
+    ///   the library on drop
+    /// * Any pointers passed to Spout must be valid for as long as Spout uses them
+    /// * Any pointers returned from Spout are owned by Spout and must not be freed
+    #[cfg(feature = "unstable-ffi")]
+    pub unsafe fn as_raw(&mut self) -> Result<Pin<&mut ffi::SPOUTLIBRARY>> {
+        match self.library {
+            Some(v) => Ok(as_pin(v)),
+            None => Err(Error::NoHandle),
+        }
+    }
+
+    /// Set the `invert` value used by the `_auto` send and receive methods. Defaults to `false`.
+    ///
+    /// OpenGL textures have their origin at the bottom-left while the DirectX textures that Spout
+    /// shares have their origin at the top-left. OpenGL applications should generally use `true`
+    /// so that frames are flipped into DirectX orientation when sending and back when receiving.
+    pub fn set_default_invert(&mut self, invert: bool) {
+        self.default_invert = invert;
+    }
+
+    /// Get the `invert` value used by the `_auto` send and receive methods.
+    pub fn default_invert(&self) -> bool {
+        self.default_invert
+    }
+
+    /// Skip the `is_initialized`/`is_gl_dx_ready` checks done by the shared texture methods.
+    /// Defaults to `false`.
+    ///
+    /// Each check is an extra call into Spout for every frame. If readiness has already been
+    /// verified once, e.g. with [is_gl_dx_ready](Self::is_gl_dx_ready), this can be set to
+    /// `true` to avoid that overhead. The tradeoff is that if Spout later becomes uninitialized,
+    /// e.g. because the sender closed, those methods silently return `false` or a texture ID of
+    /// 0 instead of an [Error::UnexpectedValue].
+    pub fn assume_ready(&mut self, ready: bool) {
+        self.assume_ready = ready;
+    }
+
+    /// Check that the `fbo_id` passed to [send_fbo](Self::send_fbo) is the currently bound FBO.
+    /// Defaults to `false`.
+    ///
+    /// Sending an FBO that is not bound silently produces a black frame. This is meant for
+    /// debugging, since every send then queries `GL_FRAMEBUFFER_BINDING` from OpenGL, which can
+    /// stall the pipeline.
+    pub fn validate_fbo(&mut self, validate: bool) {
+        self.validate_fbo = validate;
+    }
+
+    /// Return an [Error::UnexpectedValue] if `fbo_id` is not the currently bound FBO.
+    fn ensure_fbo_bound(&self, fbo_id: GLuint, context: &str) -> Result<()> {
+        let mut bound = 0_i32;
+        unsafe {
+            gl::glGetIntegerv(gl::GL_FRAMEBUFFER_BINDING, &mut bound);
+        }
+
+        if bound as u32 != fbo_id.0 {
+            return Err(Error::UnexpectedValue {
+                context: format!("{context}: FBO {} is not bound, {bound} is", fbo_id.0),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Drop all [CString]s cached for the name arguments of the frame sync and memory buffer
+    /// methods.
+    ///
+    /// At most 16 names are cached, so this is only needed to free them early.
+    pub fn clear_cstring_cache(&mut self) {
+        self.cstring_cache.clear();
+    }
+
+    /// Get a [CString] for `value`, reusing the allocation from an earlier call with the same
+    /// value.
+    ///
+    /// The returned reference is only valid until the next call, which may evict it.
+    fn cached_cstring(&mut self, context: &str, value: &str) -> Result<&CStr> {
+        match self.cstring_cache.iter().position(|(k, _)| k == value) {
+            Some(idx) => {
+                let entry = self.cstring_cache.remove(idx);
+                self.cstring_cache.push(entry);
+            }
+            None => {
+                let c_value = match CString::new(value) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        return Err(Error::FfiTypeInto {
+                            ffi_type: FfiType::CString,
+                            context: format!("{context}: {e}"),
+                        })
+                    }
+                };
+
+                if self.cstring_cache.len() >= CSTRING_CACHE_CAPACITY {
+                    self.cstring_cache.remove(0);
+                }
+                self.cstring_cache.push((value.to_string(), c_value));
+            }
+        }
+
+        match self.cstring_cache.last() {
+            Some((_, v)) => Ok(v.as_c_str()),
+            None => Err(Error::UnexpectedValue {
+                context: format!("{context}: CString cache is empty"),
+            }),
+        }
+    }
+
+    /// Return an [Error::UnexpectedValue] if Spout is not initialized or GL/DX interop is not
+    /// available, unless [assume_ready](Self::assume_ready) is set.
+    fn ensure_ready(&mut self, context: &str) -> Result<()> {
+        if self.assume_ready {
+            return Ok(());
+        }
+
+        if !self.is_initialized()? {
+            return Err(Error::UnexpectedValue {
+                context: format!("{context}: Spout is not initialized"),
+            });
+        }
+        if !self.is_gl_dx_ready()? {
+            return Err(Error::UnexpectedValue {
+                context: format!("{context}: GL/DX interop is not available"),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// View this as a [Sender], which only exposes the getters for this application's sender.
+    pub fn as_sender(&mut self) -> Sender<'_> {
+        Sender {
+            spout: self,
+            release_on_drop: false,
+        }
+    }
+
+    /// View this as a [Receiver], which only exposes the getters for the sender being received.
+    pub fn as_receiver(&mut self) -> Receiver<'_> {
+        Receiver { spout: self }
+    }
+
+    /// Set the sender name.
+    ///
+    /// Spout may add a suffix if the name is already taken, so the resulting name is read back
+    /// once and cached for [sender_name](Self::sender_name). If reading it back fails, `name` is
+    /// cached instead, since the name has already been set.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn set_sender_name<T: AsRef<str>>(&mut self, name: T) -> Result<()> {
+        let lib = unsafe { library!(self.library) };
+
+        let name = name.as_ref();
+        let c_name = match CString::new(name) {
+            Ok(v) => v,
             Err(e) => {
                 return Err(Error::FfiTypeInto {
                     ffi_type: FfiType::CString,
@@ -259,12 +1415,24 @@ impl RustySpout {
         };
 
         unsafe {
-            lib.SetSenderName(name.as_ptr());
+            lib.SetSenderName(c_name.as_ptr());
         }
 
+        self.sender_name = Some(self.get_name().unwrap_or_else(|_| name.to_owned()));
+
         Ok(())
     }
 
+    /// Get the sender name cached by [set_sender_name](Self::set_sender_name) or
+    /// [create_sender](Self::create_sender), without calling Spout.
+    ///
+    /// Returns `None` if neither has been called. A sender started by one of the send methods
+    /// without setting a name uses the executable name, which is only available from
+    /// [get_name](Self::get_name).
+    pub fn sender_name(&self) -> Option<&str> {
+        self.sender_name.as_deref()
+    }
+
     /// Set the sender DX11 shared texture format. `format` is actually a Windows `DWORD`,
     /// which _should_ be covered by `c_long`.
     ///
@@ -283,6 +1451,10 @@ impl RustySpout {
     /// Close sender and free resources. A sender is created or updated by all
     /// sending functions.
     ///
+    /// `msec` is how long Spout may wait for receivers to finish with the shared texture before
+    /// the sharing handle is closed. Most callers should use
+    /// [release_sender_default](Self::release_sender_default) instead.
+    ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
     ///
@@ -291,10 +1463,22 @@ impl RustySpout {
         let lib = unsafe { library!(self.library) };
 
         lib.ReleaseSender(msec);
+        if self.role == Some(Role::Sender) {
+            self.role = None;
+        }
 
         Ok(())
     }
 
+    /// [release_sender](Self::release_sender) with the SDK's default `msec` of 0, closing the
+    /// sender without waiting.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn release_sender_default(&mut self) -> Result<()> {
+        self.release_sender(c_ulong(0))
+    }
+
     /// Send a texture attached to an FBO.
     /// * The FBO must be currently bound
     /// * The sending texture can be larger than the size that the sender is set up for
@@ -302,6 +1486,8 @@ impl RustySpout {
     ///     for FreeFrame plugins. (The 2.006 equivalent is DrawToSharedTexture).
     /// * To send the OpenGL default framebuffer, specify "0" for the `fbo_id`, `width`, and `height`.
     ///
+    /// Whether the FBO is bound is only checked if [validate_fbo](Self::validate_fbo) is set.
+    ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
     ///
@@ -315,7 +1501,26 @@ impl RustySpout {
     ) -> Result<bool> {
         let lib = unsafe { library!(self.library) };
 
-        Ok(lib.SendFbo(fbo_id, width.into(), height.into(), invert))
+        if self.validate_fbo {
+            self.ensure_fbo_bound(fbo_id, "send_fbo")?;
+        }
+
+        let success = lib.SendFbo(fbo_id, width.into(), height.into(), invert);
+        if success {
+            self.role = Some(Role::Sender);
+        }
+
+        Ok(success)
+    }
+
+    /// [send_fbo](Self::send_fbo) using the [default invert](Self::set_default_invert).
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// No safety guarantees can be made about the `fbo_id`.
+    pub fn send_fbo_auto(&mut self, fbo_id: GLuint, width: u32, height: u32) -> Result<bool> {
+        self.send_fbo(fbo_id, width, height, self.default_invert)
     }
 
     /// Send an OpenGL texture.
@@ -324,6 +1529,14 @@ impl RustySpout {
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
     ///
     /// No safety guarantees can be made about the `texture_id`, `texture_target`, or `host_fbo`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "trace",
+            skip_all,
+            fields(width = width, height = height, frame = tracing::field::Empty)
+        )
+    )]
     pub fn send_texture(
         &mut self,
         texture_id: GLuint,
@@ -335,14 +1548,103 @@ impl RustySpout {
     ) -> Result<bool> {
         let lib = unsafe { library!(self.library) };
 
-        Ok(lib.SendTexture(
+        let success = lib.SendTexture(
             texture_id,
             texture_target,
             width.into(),
             height.into(),
             invert,
             host_fbo,
-        ))
+        );
+        if success {
+            self.role = Some(Role::Sender);
+        }
+
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::Span::current();
+            if !span.is_disabled() {
+                if let Ok(frame) = self.get_frame() {
+                    span.record("frame", frame);
+                }
+            }
+        }
+
+        Ok(success)
+    }
+
+    /// [send_texture](Self::send_texture) using the [default invert](Self::set_default_invert).
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// No safety guarantees can be made about the `texture_id`, `texture_target`, or `host_fbo`.
+    pub fn send_texture_auto(
+        &mut self,
+        texture_id: GLuint,
+        texture_target: GLuint,
+        width: u32,
+        height: u32,
+        host_fbo: GLuint,
+    ) -> Result<bool> {
+        self.send_texture(
+            texture_id,
+            texture_target,
+            width,
+            height,
+            self.default_invert,
+            host_fbo,
+        )
+    }
+
+    /// [send_texture](Self::send_texture) for applications that do not render into an FBO.
+    ///
+    /// Spout copies the texture using its own FBO, and then binds `host_fbo` again so that the
+    /// application can keep drawing into it. `SendTexture` has no overload without `host_fbo`,
+    /// it defaults to 0, which restores the default framebuffer. That is what this passes, so
+    /// use [send_texture](Self::send_texture) instead whenever an FBO is bound while sending.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// No safety guarantees can be made about the `texture_id` or `texture_target`.
+    pub fn send_texture_simple(
+        &mut self,
+        texture_id: GLuint,
+        texture_target: GLuint,
+        width: u32,
+        height: u32,
+        invert: bool,
+    ) -> Result<bool> {
+        self.send_texture(texture_id, texture_target, width, height, invert, c_uint(0))
+    }
+
+    /// [send_texture](Self::send_texture), then signal the sync event so that receivers blocked
+    /// in [receive_next_frame](Self::receive_next_frame) wake up.
+    ///
+    /// The event is signalled with this sender's own name, and only after a successful send.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// No safety guarantees can be made about the `texture_id`, `texture_target`, or `host_fbo`.
+    pub fn send_frame_and_signal(
+        &mut self,
+        texture_id: GLuint,
+        texture_target: GLuint,
+        width: u32,
+        height: u32,
+        invert: bool,
+        host_fbo: GLuint,
+    ) -> Result<bool> {
+        if !self.send_texture(texture_id, texture_target, width, height, invert, host_fbo)? {
+            return Ok(false);
+        }
+
+        let name = self.get_name()?;
+        self.set_frame_sync(name)?;
+
+        Ok(true)
     }
 
     /// Send image pixels. NOTE: this is very slow.
@@ -363,37 +1665,133 @@ impl RustySpout {
 
         let success =
             unsafe { lib.SendImage(pixels, width.into(), height.into(), gl_format, invert) };
+        if success {
+            self.role = Some(Role::Sender);
+        }
 
         Ok(success)
     }
 
-    /// Gets the sender name.
+    /// Send image pixels from a slice. NOTE: this is very slow.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// The length of `pixels` is checked against the dimensions and `format` before sending. An
+    /// [Error::UnexpectedValue] is returned for float formats, see [PixelFormat::is_float].
+    ///
+    /// Spout only applies `invert` while copying through OpenGL. If this application's sender is
+    /// sharing through the CPU (see [get_cpu](Self::get_cpu)), a flipped copy of `pixels` is made
+    /// with [flip_vertical] and sent instead.
+    pub fn send_image_slice(
+        &mut self,
+        pixels: &[u8],
+        width: u32,
+        height: u32,
+        format: PixelFormat,
+        invert: bool,
+    ) -> Result<bool> {
+        format.ensure_8_bit("send_image_slice")?;
+
+        let expected = format.buffer_size(width, height);
+        if pixels.len() != expected {
+            return Err(Error::UnexpectedValue {
+                context: format!(
+                    "send_image_slice: expected {expected} bytes, got {}",
+                    pixels.len()
+                ),
+            });
+        }
+
+        if invert && self.get_cpu()? {
+            let mut flipped = pixels.to_vec();
+            flip_vertical(&mut flipped, width, height, format.bytes_per_pixel());
+
+            return self.send_image(flipped.as_ptr(), width, height, format.gl_format(), false);
+        }
+
+        self.send_image(pixels.as_ptr(), width, height, format.gl_format(), invert)
+    }
+
+    /// [send_image_slice](Self::send_image_slice) using the
+    /// [default invert](Self::set_default_invert).
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn send_image_slice_auto(
+        &mut self,
+        pixels: &[u8],
+        width: u32,
+        height: u32,
+        format: PixelFormat,
+    ) -> Result<bool> {
+        self.send_image_slice(pixels, width, height, format, self.default_invert)
+    }
+
+    /// Send pixels, the simplest way to send.
+    ///
+    /// If a name was set with [set_sender_name](Self::set_sender_name), the sender is created
+    /// with that name on the first call and updated whenever the dimensions change. Otherwise
+    /// Spout creates a sender named after the executable. The pixels are sent through the CPU
+    /// with [send_image_slice](Self::send_image_slice) and the
+    /// [default invert](Self::set_default_invert), so no OpenGL is needed.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn send(
+        &mut self,
+        pixels: &[u8],
+        width: u32,
+        height: u32,
+        format: PixelFormat,
+    ) -> Result<bool> {
+        if let Some(name) = self.sender_name.clone() {
+            let ready = if self.role != Some(Role::Sender) {
+                self.create_sender(name.as_str(), width, height, c_ulong(0))?
+            } else if self.get_width()? != width || self.get_height()? != height {
+                self.update_sender(name.as_str(), width, height)?
+            } else {
+                true
+            };
+
+            if !ready {
+                return Ok(false);
+            }
+        }
+
+        self.send_image_slice(pixels, width, height, format, self.default_invert)
+    }
+
+    /// Send a generated [TestPattern].
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
-    /// An [Error] is returned if the name is a `nullptr`.
+    pub fn send_test_pattern(
+        &mut self,
+        pattern: TestPattern,
+        width: u32,
+        height: u32,
+    ) -> Result<bool> {
+        let pixels = pattern.pixels(width, height);
+
+        self.send_image_slice(&pixels, width, height, PixelFormat::Rgba, false)
+    }
+
+    /// Get the name of this application's sender. For use when sending.
     ///
-    /// #Panic
-    /// Panics if the string is not nul terminated.
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    /// An [Error] is returned if the name is a `nullptr` or is not nul terminated.
     pub fn get_name(&mut self) -> Result<String> {
         let lib = unsafe { library!(self.library) };
 
         let name = lib.GetName();
-        if name.is_null() {
-            return Err(Error::NullPtr);
-        }
 
-        let name = unsafe { CStr::from_ptr(name) };
-        match name.to_str() {
-            Ok(v) => Ok(v.to_string()),
-            Err(e) => Err(Error::FfiTypeFrom {
-                ffi_type: FfiType::CStr,
-                context: format!("get_name: {e}"),
-            }),
-        }
+        // Spout stores names in a buffer of MAX_SENDER_NAME_LEN bytes
+        unsafe { read_bounded_cstr(name, MAX_SENDER_NAME_LEN) }
     }
 
-    /// Get the sender width.
+    /// Get the width of this application's sender. For use when sending.
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
@@ -403,7 +1801,7 @@ impl RustySpout {
         Ok(lib.GetWidth().0)
     }
 
-    /// Get the sender height.
+    /// Get the height of this application's sender. For use when sending.
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
@@ -413,7 +1811,7 @@ impl RustySpout {
         Ok(lib.GetHeight().0)
     }
 
-    /// Get the sender frame rate.
+    /// Get the frame rate of this application's sender. For use when sending.
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
@@ -423,7 +1821,7 @@ impl RustySpout {
         Ok(lib.GetFps())
     }
 
-    /// Get the sender frame number.
+    /// Get the frame number of this application's sender. For use when sending.
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
@@ -433,7 +1831,20 @@ impl RustySpout {
         Ok(lib.GetFrame().0)
     }
 
-    /// Get the sender share handle.
+    /// Get the sender frame number as a `u64` that does not go negative or wrap around.
+    ///
+    /// The raw `i32` from [get_frame](Self::get_frame) is tracked between calls, so this
+    /// must be called regularly (at least once every `i32::MAX` frames) to stay accurate.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn get_frame_u64(&mut self) -> Result<u64> {
+        let frame = self.get_frame()?;
+
+        Ok(self.frame_counter.update(frame))
+    }
+
+    /// Get the share handle of this application's sender. For use when sending.
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
@@ -445,7 +1856,7 @@ impl RustySpout {
         Ok(lib.GetHandle())
     }
 
-    /// Get the sender sharing method.
+    /// Get the sharing method of this application's sender. For use when sending.
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
@@ -455,7 +1866,7 @@ impl RustySpout {
         Ok(lib.GetCPU())
     }
 
-    /// Get the sender GL/DX hardware compatibility.
+    /// Get the GL/DX hardware compatibility of this application's sender. For use when sending.
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
@@ -465,11 +1876,29 @@ impl RustySpout {
         Ok(lib.GetGLDX())
     }
 
+    /// Get the number of receivers connected to this application's sender. For use when sending.
+    ///
+    /// # Important
+    /// Spout does not track receivers, so this always returns [Error::Unbindable]. Senders and
+    /// receivers only share the texture and sender info, and nothing is written when a receiver
+    /// connects or disconnects.
+    ///
+    /// To skip rendering when nobody is watching, have receivers announce themselves instead, e.g.
+    /// by writing to a memory buffer with [write_memory_buffer](Self::write_memory_buffer), or by
+    /// signalling with [set_frame_sync](Self::set_frame_sync) and checking for it with
+    /// [wait_frame_sync](Self::wait_frame_sync).
+    pub fn get_receiver_count(&mut self) -> Result<i32> {
+        Err(Error::Unbindable)
+    }
+
     /// Specify a sender for connection.
     /// * If a name is specified, the receiver will not connect to any other unless the user selects one
     /// * If that sender closes, the receiver will wait for the nominated sender to open
     /// * If no name is specified, the receiver will connect to the active sender
     ///
+    /// An empty `sender_name` is passed to Spout as a `nullptr`, which is how the SDK expects
+    /// "no name" to be specified.
+    ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
     ///
@@ -477,17 +1906,10 @@ impl RustySpout {
     pub fn set_receiver_name<T: AsRef<str>>(&mut self, sender_name: T) -> Result<()> {
         let lib = unsafe { library!(self.library) };
 
-        let name = match CString::new(sender_name.as_ref()) {
-            Ok(v) => v,
-            Err(e) => {
-                return Err(Error::FfiTypeInto {
-                    ffi_type: FfiType::CString,
-                    context: format!("set_receiver_name: {e}"),
-                })
-            }
-        };
+        let name = receiver_name("set_receiver_name", sender_name.as_ref())?;
+
         unsafe {
-            lib.SetReceiverName(name.as_ptr());
+            lib.SetReceiverName(name_ptr(&name));
         }
 
         Ok(())
@@ -501,6 +1923,11 @@ impl RustySpout {
         let lib = unsafe { library!(self.library) };
 
         lib.ReleaseReceiver();
+        if self.role == Some(Role::Receiver) {
+            self.role = None;
+        }
+        self.relay_source = None;
+        self.receive_texture_size = None;
 
         Ok(())
     }
@@ -514,6 +1941,18 @@ impl RustySpout {
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "trace",
+            skip_all,
+            fields(
+                width = tracing::field::Empty,
+                height = tracing::field::Empty,
+                frame = tracing::field::Empty
+            )
+        )
+    )]
     pub fn receive_texture(
         &mut self,
         texture_id: GLuint,
@@ -523,52 +1962,425 @@ impl RustySpout {
     ) -> Result<bool> {
         let lib = unsafe { library!(self.library) };
 
-        Ok(lib.ReceiveTexture(texture_id, texture_target, invert, host_fbo))
+        let success = lib.ReceiveTexture(texture_id, texture_target, invert, host_fbo);
+        if success {
+            self.role = Some(Role::Receiver);
+        }
+
+        #[cfg(feature = "tracing")]
+        self.record_receive_span();
+
+        Ok(success)
     }
 
-    /// Receive image pixels.
+    /// [receive_texture](Self::receive_texture) that also returns the dimensions of the sender.
     ///
-    /// Connect to a sender and inform the application to update the receiving buffer if it has changed
-    /// dimensions. For no change, copy the sender shared texture to the pixel buffer.
+    /// Returns `None` if the receive failed or if not connected to a sender.
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
-    ///
-    /// It is up to the developer to make sure the `pixels` buffer is large enough.
-    pub fn receive_image(
+    pub fn receive_texture_sized(
         &mut self,
-        pixels: *const u8,
-        gl_format: GLenum,
+        texture_id: GLuint,
+        texture_target: GLuint,
         invert: bool,
         host_fbo: GLuint,
-    ) -> Result<bool> {
-        let lib = unsafe { library!(self.library) };
-
-        let success = unsafe { lib.ReceiveImage(pixels.cast_mut(), gl_format, invert, host_fbo) };
+    ) -> Result<Option<(u32, u32)>> {
+        if !self.receive_texture(texture_id, texture_target, invert, host_fbo)?
+            || !self.is_connected()?
+        {
+            return Ok(None);
+        }
 
-        Ok(success)
+        Ok(Some((self.get_sender_width()?, self.get_sender_height()?)))
     }
 
-    /// Query whether the sender has changed.
+    /// [receive_texture](Self::receive_texture) into a texture of `width` x `height`, checking
+    /// that it matches the sender first.
     ///
-    /// Checked at every cycle before receiving data.
+    /// Spout copies into the texture without knowing its size, so a texture that does not match
+    /// the sender receives garbage. While connected, an [Error::UnexpectedValue] with both sizes
+    /// is returned on a mismatch instead of receiving. While not connected the receive goes ahead
+    /// so that it can connect, after which [is_updated](Self::is_updated) reports that the
+    /// texture has to be resized to the sender.
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
-    pub fn is_updated(&mut self) -> Result<bool> {
-        let lib = unsafe { library!(self.library) };
+    ///
+    /// No safety guarantees can be made about the texture id or target.
+    pub fn receive_texture_checked(
+        &mut self,
+        texture_id: GLuint,
+        texture_target: GLuint,
+        width: u32,
+        height: u32,
+        invert: bool,
+        host_fbo: GLuint,
+    ) -> Result<bool> {
+        if self.is_connected()? {
+            let sender_width = self.get_sender_width()?;
+            let sender_height = self.get_sender_height()?;
+            if (sender_width, sender_height) != (width, height) {
+                return Err(Error::UnexpectedValue {
+                    context: format!(
+                        "receive_texture_checked: texture is {width}x{height}, sender is \
+                        {sender_width}x{sender_height}"
+                    ),
+                });
+            }
+        }
 
-        Ok(lib.IsUpdated())
+        self.receive_texture(texture_id, texture_target, invert, host_fbo)
     }
 
-    /// Query sender connection.
-    ///
-    /// If the sender closes, receiving functions return `false`.
+    /// [receive_texture](Self::receive_texture) using the
+    /// [default invert](Self::set_default_invert).
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
-    pub fn is_connected(&mut self) -> Result<bool> {
-        let lib = unsafe { library!(self.library) };
+    pub fn receive_texture_auto(
+        &mut self,
+        texture_id: GLuint,
+        texture_target: GLuint,
+        host_fbo: GLuint,
+    ) -> Result<bool> {
+        self.receive_texture(texture_id, texture_target, self.default_invert, host_fbo)
+    }
+
+    /// Receive image pixels.
+    ///
+    /// Connect to a sender and inform the application to update the receiving buffer if it has changed
+    /// dimensions. For no change, copy the sender shared texture to the pixel buffer.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// It is up to the developer to make sure the `pixels` buffer is large enough.
+    ///
+    /// An [Error::UnexpectedValue] is returned if `gl_format` is not one of
+    /// [supported_gl_formats], since Spout would otherwise silently produce a black frame.
+    pub fn receive_image(
+        &mut self,
+        pixels: *const u8,
+        gl_format: GLenum,
+        invert: bool,
+        host_fbo: GLuint,
+    ) -> Result<bool> {
+        let lib = unsafe { library!(self.library) };
+
+        if !supported_gl_formats().contains(&gl_format) {
+            return Err(Error::UnexpectedValue {
+                context: format!("receive_image: unsupported GL format {:#X}", gl_format.0),
+            });
+        }
+
+        let success = unsafe { lib.ReceiveImage(pixels.cast_mut(), gl_format, invert, host_fbo) };
+        if success {
+            self.role = Some(Role::Receiver);
+        }
+
+        Ok(success)
+    }
+
+    /// Receive image pixels into a buffer that is resized to fit the sender.
+    ///
+    /// If the sender has changed, `buffer` is resized and no pixels are copied until the next
+    /// call, which is reported as [ReceiveStatus::Updated]. This consumes Spout's updated flag, so
+    /// check the returned status instead of calling [is_updated](Self::is_updated). An
+    /// [Error::UnexpectedValue] is returned for float formats, see [PixelFormat::is_float].
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "trace",
+            skip_all,
+            fields(
+                width = tracing::field::Empty,
+                height = tracing::field::Empty,
+                frame = tracing::field::Empty
+            )
+        )
+    )]
+    pub fn receive_image_into(
+        &mut self,
+        buffer: &mut Vec<u8>,
+        format: PixelFormat,
+        invert: bool,
+        host_fbo: GLuint,
+    ) -> Result<ReceiveStatus> {
+        format.ensure_8_bit("receive_image_into")?;
+
+        let size = format.buffer_size(self.get_sender_width()?, self.get_sender_height()?);
+        buffer.resize(size, 0);
+
+        let success = self.receive_image(
+            buffer.as_mut_ptr().cast_const(),
+            format.gl_format(),
+            invert,
+            host_fbo,
+        )?;
+
+        let status = if !success {
+            ReceiveStatus::NotConnected
+        } else if self.is_updated()? {
+            let size = format.buffer_size(self.get_sender_width()?, self.get_sender_height()?);
+            buffer.resize(size, 0);
+            ReceiveStatus::Updated
+        } else {
+            ReceiveStatus::Received
+        };
+
+        #[cfg(feature = "tracing")]
+        self.record_receive_span();
+
+        Ok(status)
+    }
+
+    /// Record the sender dimensions and frame number on the current receive span.
+    ///
+    /// The elapsed time is the duration of the span itself, as reported by the subscriber. Nothing
+    /// is queried without a subscriber for the span, and fields that fail to be read are left
+    /// empty instead of failing the receive.
+    #[cfg(feature = "tracing")]
+    fn record_receive_span(&mut self) {
+        let span = tracing::Span::current();
+        if span.is_disabled() {
+            return;
+        }
+
+        if let Ok(width) = self.get_sender_width() {
+            span.record("width", width);
+        }
+        if let Ok(height) = self.get_sender_height() {
+            span.record("height", height);
+        }
+        if let Ok(frame) = self.get_sender_frame() {
+            span.record("frame", frame);
+        }
+    }
+
+    /// [receive_image_into](Self::receive_image_into) using the
+    /// [default invert](Self::set_default_invert).
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn receive_image_into_auto(
+        &mut self,
+        buffer: &mut Vec<u8>,
+        format: PixelFormat,
+        host_fbo: GLuint,
+    ) -> Result<ReceiveStatus> {
+        self.receive_image_into(buffer, format, self.default_invert, host_fbo)
+    }
+
+    /// Receive image pixels converted to `desired`, whatever the format of the sender.
+    ///
+    /// Pixels are received in the 8-bit [PixelFormat] matching the sender's [DxgiFormat], or
+    /// [PixelFormat::Rgba] for formats without one, then converted with [convert::convert]. If
+    /// that is already `desired`, the received pixels are returned without a copy. Returns `None`
+    /// if not connected or if the sender was updated. An [Error::UnexpectedValue] is returned if
+    /// `desired` is a float format, see [PixelFormat::is_float].
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn receive_image_as(
+        &mut self,
+        desired: PixelFormat,
+        invert: bool,
+        host_fbo: GLuint,
+    ) -> Result<Option<Vec<u8>>> {
+        desired.ensure_8_bit("receive_image_as")?;
+
+        let sender_format = match self.get_sender_format()? {
+            c_ulong(0) => Some(DxgiFormat::B8G8R8A8Unorm),
+            v => DxgiFormat::try_from(v).ok(),
+        };
+        let native = sender_format
+            .and_then(|v| v.pixel_format())
+            .filter(|v| !v.is_float())
+            .unwrap_or(PixelFormat::Rgba);
+
+        let mut buffer = Vec::new();
+        if !self
+            .receive_image_into(&mut buffer, native, invert, host_fbo)?
+            .is_received()
+        {
+            return Ok(None);
+        }
+
+        if native == desired {
+            return Ok(Some(buffer));
+        }
+
+        let width = self.get_sender_width()?;
+        let height = self.get_sender_height()?;
+
+        convert::convert(
+            &buffer,
+            width,
+            height,
+            native.buffer_size(width, 1),
+            native,
+            desired,
+        )
+        .map(Some)
+    }
+
+    /// Receive image pixels into `buffer`, only returning them if they are from a new frame.
+    ///
+    /// Spout only copies pixels when the sender has produced a new frame. The last frame number
+    /// is also tracked, so the same frame is never returned twice even if `is_frame_new` reports
+    /// otherwise. Returns `None` if not connected, if the sender was updated, or if there is no
+    /// new frame.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn receive_image_if_new<'a>(
+        &mut self,
+        buffer: &'a mut Vec<u8>,
+        format: PixelFormat,
+        invert: bool,
+        host_fbo: GLuint,
+    ) -> Result<Option<&'a [u8]>> {
+        let status = self.receive_image_into(buffer, format, invert, host_fbo)?;
+
+        let frame = match status {
+            ReceiveStatus::Received if self.is_frame_new()? => Some(self.get_sender_frame()?),
+            _ => None,
+        };
+        if !accept_frame(status, frame, &mut self.last_received_frame) {
+            return Ok(None);
+        }
+
+        Ok(Some(buffer.as_slice()))
+    }
+
+    /// Receive the next frame as [PixelFormat::Rgba] pixels, the simplest way to receive.
+    ///
+    /// Uses [receive_image_if_new](Self::receive_image_if_new) with an internal buffer that
+    /// grows to fit the sender, and the [default invert](Self::set_default_invert). Returns
+    /// `None` if not connected, if the sender changed size, or if there is no new frame. The
+    /// returned [Frame] borrows the internal buffer, so no pixels are copied out.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn recv(&mut self) -> Result<Option<Frame<'_>>> {
+        let format = PixelFormat::Rgba;
+
+        let mut buffer = std::mem::take(&mut self.recv_buffer);
+        let received = self
+            .receive_image_if_new(&mut buffer, format, self.default_invert, c_uint(0))
+            .map(|v| v.is_some());
+        self.recv_buffer = buffer;
+
+        if !received? {
+            return Ok(None);
+        }
+
+        let width = self.get_sender_width()?;
+        let height = self.get_sender_height()?;
+
+        Ok(Some(Frame {
+            pixels: &self.recv_buffer,
+            width,
+            height,
+            format,
+        }))
+    }
+
+    /// Receive image pixels and upload them into a [wgpu::Buffer], for use in compute shaders.
+    ///
+    /// The frame is staged through the CPU with [receive_image_into](Self::receive_image_into)
+    /// and written with [wgpu::Queue::write_buffer]. wgpu has no stable way to import a DX11 shared
+    /// handle, so a zero-copy path is not possible. `buffer` must have `COPY_DST` usage and be at
+    /// least [PixelFormat::buffer_size] bytes for the sender dimensions. Nothing is written if
+    /// the sender was updated.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    #[cfg(feature = "wgpu")]
+    pub fn receive_into_wgpu_buffer(
+        &mut self,
+        queue: &wgpu::Queue,
+        buffer: &wgpu::Buffer,
+        format: PixelFormat,
+        invert: bool,
+        host_fbo: GLuint,
+    ) -> Result<bool> {
+        let mut staging = std::mem::take(&mut self.wgpu_staging);
+        let status = self.receive_image_into(&mut staging, format, invert, host_fbo);
+        self.wgpu_staging = staging;
+
+        if !status?.is_received() {
+            return Ok(false);
+        }
+
+        let len = self.wgpu_staging.len();
+        if len as u64 > buffer.size() || len as u64 % wgpu::COPY_BUFFER_ALIGNMENT != 0 {
+            return Err(Error::UnexpectedValue {
+                context: format!(
+                    "receive_into_wgpu_buffer: {len} bytes cannot be written to a buffer of {} bytes",
+                    buffer.size()
+                ),
+            });
+        }
+
+        queue.write_buffer(buffer, 0, &self.wgpu_staging);
+
+        Ok(true)
+    }
+
+    /// Query whether the sender has changed.
+    ///
+    /// Checked at every cycle before receiving data.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn is_updated(&mut self) -> Result<bool> {
+        let lib = unsafe { library!(self.library) };
+
+        let updated = lib.IsUpdated();
+        if updated {
+            self.sender_info_cache.clear();
+        }
+
+        Ok(updated)
+    }
+
+    /// Check if the receiving texture has to be recreated at a new size. For use when receiving,
+    /// after [receive_texture](Self::receive_texture).
+    ///
+    /// [is_updated](Self::is_updated) is also `true` when the sender restarts at the same size,
+    /// so reallocating whenever it is set does more work than needed. This instead compares the
+    /// sender's size with the size it last returned `true` for, so it is `true` exactly once per
+    /// resize, and on the first call after connecting. Resize the texture to
+    /// [get_sender_width](Self::get_sender_width) x [get_sender_height](Self::get_sender_height)
+    /// when it is. Always `false` while not connected.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn texture_recreate_needed(&mut self) -> Result<bool> {
+        let size = (self.get_sender_width()?, self.get_sender_height()?);
+        if size.0 == 0 || size.1 == 0 {
+            return Ok(false);
+        }
+
+        let changed = self.receive_texture_size != Some(size);
+        self.receive_texture_size = Some(size);
+
+        Ok(changed)
+    }
+
+    /// Query sender connection.
+    ///
+    /// If the sender closes, receiving functions return `false`.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn is_connected(&mut self) -> Result<bool> {
+        let lib = unsafe { library!(self.library) };
 
         Ok(lib.IsConnected())
     }
@@ -586,33 +2398,21 @@ impl RustySpout {
         Ok(lib.IsFrameNew())
     }
 
-    /// Get the sender name.
+    /// Get the name of the sender this receiver is connected to. For use when receiving.
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
-    /// Returns [Error] if the name is a `nullptr`.
-    ///
-    /// # Panic
-    /// Panics if the name is not nul terminated.
+    /// Returns [Error] if the name is a `nullptr` or is not nul terminated.
     pub fn get_sender_name(&mut self) -> Result<String> {
         let lib = unsafe { library!(self.library) };
 
         let name = lib.GetSenderName();
-        if name.is_null() {
-            return Err(Error::NullPtr);
-        }
 
-        let name = unsafe { CStr::from_ptr(name) };
-        match name.to_str() {
-            Ok(v) => Ok(v.to_string()),
-            Err(e) => Err(Error::FfiTypeFrom {
-                ffi_type: FfiType::CStr,
-                context: format!("get_sender_name: {e}"),
-            }),
-        }
+        // Spout stores names in a buffer of MAX_SENDER_NAME_LEN bytes
+        unsafe { read_bounded_cstr(name, MAX_SENDER_NAME_LEN) }
     }
 
-    /// Get the sender width.
+    /// Get the width of the sender this receiver is connected to. For use when receiving.
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
@@ -624,7 +2424,7 @@ impl RustySpout {
         Err(Error::NoHandle)
     }
 
-    /// Get the sender height.
+    /// Get the height of the sender this receiver is connected to. For use when receiving.
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
@@ -636,7 +2436,8 @@ impl RustySpout {
         Err(Error::NoHandle)
     }
 
-    /// Get the sender DirectX texture format.
+    /// Get the DirectX texture format of the sender this receiver is connected to. For use when
+    /// receiving.
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
@@ -648,7 +2449,7 @@ impl RustySpout {
         Err(Error::NoHandle)
     }
 
-    /// Get the sender frame rate.
+    /// Get the frame rate of the sender this receiver is connected to. For use when receiving.
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
@@ -660,7 +2461,7 @@ impl RustySpout {
         Err(Error::NoHandle)
     }
 
-    /// Get the sender frame number.
+    /// Get the frame number of the sender this receiver is connected to. For use when receiving.
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
@@ -670,7 +2471,20 @@ impl RustySpout {
         Ok(lib.GetSenderFrame().0)
     }
 
-    /// Get the received sender share handle.
+    /// Get the received sender frame number as a `u64` that does not go negative or wrap around.
+    ///
+    /// The raw `i32` from [get_sender_frame](Self::get_sender_frame) is tracked between calls,
+    /// so this must be called regularly (at least once every `i32::MAX` frames) to stay accurate.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn get_sender_frame_u64(&mut self) -> Result<u64> {
+        let frame = self.get_sender_frame()?;
+
+        Ok(self.sender_frame_counter.update(frame))
+    }
+
+    /// Get the share handle of the sender this receiver is connected to. For use when receiving.
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
@@ -682,7 +2496,7 @@ impl RustySpout {
         Ok(lib.GetSenderHandle())
     }
 
-    /// Get the received sender sharing mode.
+    /// Get the sharing mode of the sender this receiver is connected to. For use when receiving.
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
@@ -692,7 +2506,8 @@ impl RustySpout {
         Ok(lib.GetSenderCPU())
     }
 
-    /// Get the received sender GL/DX compatibility.
+    /// Get the GL/DX compatibility of the sender this receiver is connected to. For use when
+    /// receiving.
     ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
@@ -702,16 +2517,72 @@ impl RustySpout {
         Ok(lib.GetSenderGLDX())
     }
 
+    /// Get how the connected sender is sharing frames. For use when receiving.
+    ///
+    /// A sender using CPU methods is [SharingPath::Cpu]. Otherwise a sender without a shared
+    /// texture handle is writing to a memory map and is [SharingPath::GpuMemory], and one with a
+    /// handle is [SharingPath::GpuTexture]. [SharingPath::Auto] is never returned. Whether the
+    /// sender is GL/DX compatible does not affect the path, since DirectX senders share textures
+    /// without interop. An [Error::UnexpectedValue] is returned if not connected to a sender.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn sender_sharing_mode(&mut self) -> Result<SharingPath> {
+        if !self.is_connected()? {
+            return Err(Error::UnexpectedValue {
+                context: "sender_sharing_mode: not connected to a sender".to_string(),
+            });
+        }
+
+        if self.get_sender_cpu()? {
+            Ok(SharingPath::Cpu)
+        } else if self.get_sender_handle()?.is_null() {
+            Ok(SharingPath::GpuMemory)
+        } else {
+            Ok(SharingPath::GpuTexture)
+        }
+    }
+
+    /// Get the [SenderInfo] of the connected sender, or `None` if not connected.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// No safety guarantees are made about the returned `HANDLE`.
+    pub fn connected_sender_info(&mut self) -> Result<Option<SenderInfo>> {
+        let mut lib = unsafe { library!(self.library) };
+
+        if !lib.as_mut().IsConnected() {
+            return Ok(None);
+        }
+
+        Ok(Some(SenderInfo {
+            width: lib.as_mut().GetSenderWidth().0,
+            height: lib.as_mut().GetSenderHeight().0,
+            share_handle: lib.as_mut().GetSenderHandle(),
+            format: lib.as_mut().GetSenderFormat(),
+        }))
+    }
+
     /// Open the sender selection dialog.
     ///
     /// # Important
     /// **No guarantees are made about how this actually works. Use at your own risk!**
     ///
+    /// Returns an [Error] if SpoutPanel is not installed (see [is_spout_installed]), since Spout
+    /// silently does nothing in that case.
+    ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
     pub fn select_sender(&mut self) -> Result<()> {
         let lib = unsafe { library!(self.library) };
 
+        if registry::spout_panel_path()?.is_none() {
+            return Err(Error::UnexpectedValue {
+                context: "SpoutPanel not installed".to_string(),
+            });
+        }
+
         lib.SelectSender();
 
         Ok(())
@@ -751,6 +2622,43 @@ impl RustySpout {
         Ok(lib.IsFrameCountEnabled())
     }
 
+    /// Reset the frame counting statistics, e.g. after connecting to a different sender.
+    ///
+    /// The SDK has no reset, so frame counting is disabled for this application and re-enabled
+    /// with [set_frame_count](Self::set_frame_count). This closes the frame count semaphore, which
+    /// is reopened on the next send or receive. It does nothing to the SDK counter if frame
+    /// counting is not enabled. The 64-bit counts from
+    /// [get_frame_u64](Self::get_frame_u64) and [get_sender_frame_u64](Self::get_sender_frame_u64)
+    /// and the frame tracked by [receive_image_if_new](Self::receive_image_if_new) are always
+    /// reset.
+    ///
+    /// After a reset:
+    /// * [get_sender_frame](Self::get_sender_frame) reports the sender's own count once the
+    ///   semaphore is reopened. The count belongs to the sender and cannot be reset by a receiver
+    /// * [get_sender_fps](Self::get_sender_fps) restarts its measurement, so it reads the system
+    ///   refresh rate until enough new frames have been received
+    ///
+    /// # Important
+    /// Re-enabling writes Spout's global frame count setting to the registry. The value written
+    /// is the enabled state this application already uses, but it overwrites the setting if
+    /// another application or SpoutSettings changed it since this one started.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn reset_frame_count(&mut self) -> Result<()> {
+        if self.is_frame_count_enabled()? {
+            self.disable_frame_count()?;
+            // There is no per-application enable, this writes the global setting as enabled
+            self.set_frame_count(true)?;
+        }
+
+        self.frame_counter = FrameCounter::default();
+        self.sender_frame_counter = FrameCounter::default();
+        self.last_received_frame = None;
+
+        Ok(())
+    }
+
     /// Sender frame rate control.
     ///
     /// # Safety
@@ -759,6 +2667,7 @@ impl RustySpout {
         let lib = unsafe { library!(self.library) };
 
         lib.HoldFps(fps.into());
+        self.requested_fps = Some(fps);
 
         Ok(())
     }
@@ -773,6 +2682,18 @@ impl RustySpout {
         Ok(lib.GetRefreshRate())
     }
 
+    /// Get the achieved and requested sender frame rates along with the system refresh rate.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn fps_report(&mut self) -> Result<FpsReport> {
+        Ok(FpsReport {
+            achieved: self.get_fps()?,
+            requested: self.requested_fps,
+            refresh_rate: self.get_refresh_rate()?,
+        })
+    }
+
     /// Signal sync event.
     ///
     /// # Safety
@@ -782,15 +2703,7 @@ impl RustySpout {
     pub fn set_frame_sync<T: AsRef<str>>(&mut self, sender_name: T) -> Result<()> {
         let lib = unsafe { library!(self.library) };
 
-        let name = match CString::new(sender_name.as_ref()) {
-            Ok(v) => v,
-            Err(e) => {
-                return Err(Error::FfiTypeInto {
-                    ffi_type: FfiType::CString,
-                    context: format!("set_frame_sync: {e}"),
-                })
-            }
-        };
+        let name = self.cached_cstring("set_frame_sync", sender_name.as_ref())?;
 
         unsafe {
             lib.SetFrameSync(name.as_ptr());
@@ -812,21 +2725,63 @@ impl RustySpout {
     ) -> Result<bool> {
         let lib = unsafe { library!(self.library) };
 
-        let name = match CString::new(sender_name.as_ref()) {
-            Ok(v) => v,
-            Err(e) => {
-                return Err(Error::FfiTypeInto {
-                    ffi_type: FfiType::CString,
-                    context: format!("wait_frame_sync: {e}"),
-                })
-            }
-        };
+        let name = self.cached_cstring("wait_frame_sync", sender_name.as_ref())?;
 
         let success = unsafe { lib.WaitFrameSync(name.as_ptr(), timeout) };
 
         Ok(success)
     }
 
+    /// Block until the connected sender signals a new frame, then receive it.
+    ///
+    /// Uses [wait_frame_sync](Self::wait_frame_sync) with the connected sender's name, so the
+    /// sender must be calling [set_frame_sync](Self::set_frame_sync) after each send. Otherwise
+    /// every call waits for the full `timeout`. If not yet connected, a receive is done first to
+    /// find the sender. Returns `None` on timeout or if there is no sender.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn receive_next_frame(
+        &mut self,
+        timeout: Duration,
+        format: PixelFormat,
+        invert: bool,
+        host_fbo: GLuint,
+    ) -> Result<Option<FrameData>> {
+        let mut pixels = Vec::new();
+
+        if !self.is_connected()?
+            && !self
+                .receive_image_into(&mut pixels, format, invert, host_fbo)?
+                .is_connected()
+        {
+            return Ok(None);
+        }
+
+        let sender_name = self.get_sender_name()?;
+        let timeout = c_ulong(u32::try_from(timeout.as_millis()).unwrap_or(u32::MAX) as _);
+        if !self.wait_frame_sync(sender_name, timeout)? {
+            return Ok(None);
+        }
+
+        let mut status = self.receive_image_into(&mut pixels, format, invert, host_fbo)?;
+        // The buffer was only resized, so receive again to copy the pixels
+        if status == ReceiveStatus::Updated {
+            status = self.receive_image_into(&mut pixels, format, invert, host_fbo)?;
+        }
+        if !status.is_received() {
+            return Ok(None);
+        }
+
+        Ok(Some(FrameData {
+            pixels,
+            width: self.get_sender_width()?,
+            height: self.get_sender_height()?,
+            format,
+            frame: self.get_sender_frame()?,
+        }))
+    }
+
     /// Write data.
     ///
     /// # Safety
@@ -836,8 +2791,8 @@ impl RustySpout {
     pub fn write_memory_buffer<T: AsRef<str>>(&mut self, sender_name: T, data: T) -> Result<bool> {
         let lib = unsafe { library!(self.library) };
 
-        let name = str_to_cstring!("write_memory_buffer", sender_name);
         let data = str_to_cstring!("write_memory_buffer", data);
+        let name = self.cached_cstring("write_memory_buffer", sender_name.as_ref())?;
         let length = data.as_c_str().to_bytes_with_nul().len();
 
         let success =
@@ -859,7 +2814,9 @@ impl RustySpout {
     ) -> Result<(i32, String)> {
         let lib = unsafe { library!(self.library) };
 
-        let name = str_to_cstring!("read_memory_buffer", sender_name);
+        let name = self
+            .cached_cstring("read_memory_buffer", sender_name.as_ref())?
+            .as_ptr();
 
         let mut buffer = vec![1; max_length - 1];
         buffer.push(0);
@@ -883,9 +2840,8 @@ impl RustySpout {
             }
         };
 
-        let result = unsafe {
-            lib.ReadMemoryBuffer(name.as_ptr(), data.as_ptr().cast_mut(), max_length.into())
-        };
+        let result =
+            unsafe { lib.ReadMemoryBuffer(name, data.as_ptr().cast_mut(), max_length.into()) };
 
         let data = cstring_to_string!("read_memory_buffer", data);
 
@@ -899,9 +2855,12 @@ impl RustySpout {
     pub fn create_memory_buffer<T: AsRef<str>>(&mut self, name: T, length: i32) -> Result<bool> {
         let lib = unsafe { library!(self.library) };
 
-        let name = str_to_cstring!("create_memory_buffer", name);
+        let c_name = str_to_cstring!("create_memory_buffer", name);
 
-        let success = unsafe { lib.CreateMemoryBuffer(name.as_ptr(), length.into()) };
+        let success = unsafe { lib.CreateMemoryBuffer(c_name.as_ptr(), length.into()) };
+        if success {
+            self.memory_buffer_name = Some(name.as_ref().to_string());
+        }
 
         Ok(success)
     }
@@ -913,7 +2872,38 @@ impl RustySpout {
     pub fn delete_memory_buffer(&mut self) -> Result<bool> {
         let lib = unsafe { library!(self.library) };
 
-        Ok(lib.DeleteMemoryBuffer())
+        let success = lib.DeleteMemoryBuffer();
+        if success {
+            self.memory_buffer_name = None;
+        }
+
+        Ok(success)
+    }
+
+    /// Recreate the buffer from [create_memory_buffer](Self::create_memory_buffer) with a new
+    /// length, keeping its name.
+    ///
+    /// Spout cannot resize a memory buffer, so it is deleted and created again. Readers that open
+    /// the buffer in between find nothing, and any data in it is lost. Returns `false` if the
+    /// buffer could not be deleted, leaving it as it was, or could not be created again, leaving
+    /// no buffer.
+    ///
+    /// An [Error::UnexpectedValue] is returned if no buffer has been created.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn resize_memory_buffer(&mut self, new_length: i32) -> Result<bool> {
+        let Some(name) = self.memory_buffer_name.clone() else {
+            return Err(Error::UnexpectedValue {
+                context: "resize_memory_buffer: no memory buffer has been created".to_string(),
+            });
+        };
+
+        if !self.delete_memory_buffer()? {
+            return Ok(false);
+        }
+
+        self.create_memory_buffer(name, new_length)
     }
 
     /// Get the number of bytes available for data transfer.
@@ -925,7 +2915,7 @@ impl RustySpout {
     pub fn get_memory_buffer_size<T: AsRef<str>>(&mut self, name: T) -> Result<i32> {
         let lib = unsafe { library!(self.library) };
 
-        let name = str_to_cstring!("get_memory_buffer_size", name);
+        let name = self.cached_cstring("get_memory_buffer_size", name.as_ref())?;
 
         let size = unsafe { lib.GetMemoryBufferSize(name.as_ptr()) };
 
@@ -960,6 +2950,17 @@ impl RustySpout {
         Ok(())
     }
 
+    /// Enable logging to a file.
+    ///
+    /// A bare file name is placed in Spout's default log folder, `%AppData%\Spout`. For a full
+    /// path, Spout silently fails to log if the file cannot be opened, so the parent directory is
+    /// created if needed and the file is opened for writing first. An [Error::Io] is returned if
+    /// either fails. Nothing is created without a handle.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// The [CString] should be copied on the Spout side and should be safe to drop.
     pub fn enable_spout_log_file<T: AsRef<str>>(
         &mut self,
         filename: T,
@@ -967,15 +2968,66 @@ impl RustySpout {
     ) -> Result<()> {
         let lib = unsafe { library!(self.library) };
 
-        let filename = str_to_cstring!("enable_spout_log_file", filename);
+        let c_filename = str_to_cstring!("enable_spout_log_file", filename);
 
-        unsafe {
-            lib.EnableSpoutLogFile(filename.as_ptr(), append);
+        let path = Path::new(filename.as_ref());
+        if path.is_absolute() {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            // Check that the file is writable without truncating it, Spout handles `append`
+            std::fs::OpenOptions::new()
+                .append(true)
+                .create(true)
+                .open(path)?;
+        }
+
+        unsafe {
+            lib.EnableSpoutLogFile(c_filename.as_ptr(), append);
         }
 
+        self.log_file = Some(path.to_path_buf());
+
         Ok(())
     }
 
+    /// Get the path of the log file Spout is writing to.
+    ///
+    /// This is the path passed to [enable_spout_log_file](Self::enable_spout_log_file), resolved
+    /// into Spout's default log folder, `%AppData%\Spout`, for a bare file name. If no log file
+    /// was set, the default log folder is returned instead.
+    ///
+    /// The SDK resolves this path internally but `SPOUTLIBRARY` does not expose it, so it is
+    /// resolved the same way here.
+    pub fn get_spout_log_path(&mut self) -> Result<String> {
+        let path = match &self.log_file {
+            Some(v) if v.is_absolute() => v.clone(),
+            log_file => {
+                let app_data = match std::env::var_os("APPDATA") {
+                    Some(v) => PathBuf::from(v),
+                    None => {
+                        return Err(Error::UnexpectedValue {
+                            context: "get_spout_log_path: APPDATA is not set".to_string(),
+                        })
+                    }
+                };
+
+                let folder = app_data.join("Spout");
+                match log_file {
+                    Some(v) => folder.join(v),
+                    None => folder,
+                }
+            }
+        };
+
+        match path.into_os_string().into_string() {
+            Ok(v) => Ok(v),
+            Err(v) => Err(Error::UnexpectedValue {
+                context: format!("get_spout_log_path: {v:?} is not valid UTF-8"),
+            }),
+        }
+    }
+
     pub fn get_spout_log(&mut self) -> Result<String> {
         let lib = unsafe { library!(self.library) };
 
@@ -984,6 +3036,61 @@ impl RustySpout {
         Ok(log.to_string())
     }
 
+    /// Get the Spout log, replacing any invalid UTF-8 with `U+FFFD`.
+    ///
+    /// Spout writes the log in the system code page, so characters outside of ASCII may not be
+    /// valid UTF-8 and are replaced. Use [get_spout_log_bytes](Self::get_spout_log_bytes) to
+    /// decode the log some other way.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn get_spout_log_lossy(&mut self) -> Result<String> {
+        let log = self.get_spout_log_bytes()?;
+
+        Ok(String::from_utf8_lossy(&log).into_owned())
+    }
+
+    /// Get the raw bytes of the Spout log.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn get_spout_log_bytes(&mut self) -> Result<Vec<u8>> {
+        let lib = unsafe { library!(self.library) };
+
+        let log = lib.GetSpoutLog();
+        if log.is_null() {
+            return Err(Error::NullPtr);
+        }
+
+        Ok(log.as_bytes().to_vec())
+    }
+
+    /// Forward new Spout log entries to the [log] crate, returning the number of entries forwarded.
+    ///
+    /// Spout has no log callback, so this must be polled, e.g. once per frame. Each call reads
+    /// the log with [get_spout_log](Self::get_spout_log) and forwards the entries written since
+    /// the previous call, with the `spout` target. Spout must be logging to a file with
+    /// [enable_spout_log_file](Self::enable_spout_log_file) for there to be anything to read.
+    ///
+    /// To stop routing, stop calling this method. [disable_spout_log](Self::disable_spout_log)
+    /// additionally stops Spout from writing the log.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    #[cfg(feature = "log")]
+    pub fn route_spout_logs_to_log_crate(&mut self) -> Result<usize> {
+        let spout_log = self.get_spout_log()?;
+
+        let mut count = 0;
+        for line in new_spout_log_lines(&spout_log, &mut self.log_cursor) {
+            let (level, message) = parse_spout_log_line(line);
+            log::log!(target: "spout", level, "{message}");
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
     pub fn show_spout_logs(&mut self) -> Result<()> {
         let lib = unsafe { library!(self.library) };
 
@@ -1000,10 +3107,49 @@ impl RustySpout {
         Ok(())
     }
 
+    /// Set the minimum level of log entries that Spout writes.
+    ///
+    /// While [suppress_message_boxes](Self::suppress_message_boxes) is active, the level is only
+    /// applied once message boxes are allowed again.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
     pub fn set_spout_log_level(&mut self, level: SpoutLibLogLevel) -> Result<()> {
         let lib = unsafe { library!(self.library) };
 
+        if !self.message_boxes_suppressed {
+            lib.SetSpoutLogLevel(level);
+        }
+        self.log_level = Some(level);
+
+        Ok(())
+    }
+
+    /// Stop Spout from showing message boxes for fatal errors, e.g. for unattended deployments.
+    ///
+    /// `SPOUTLIBRARY` has no silent mode and there is no registry setting for it. Spout shows a
+    /// message box for every fatal log entry, so suppressing sets the log level to
+    /// `SPOUT_LOG_NONE`, which also stops console and file logging. Allowing message boxes again
+    /// restores the level last passed to [set_spout_log_level](Self::set_spout_log_level), or
+    /// Spout's default of `SPOUT_LOG_NOTICE`.
+    ///
+    /// # Important
+    /// Dialogs that Spout shows directly instead of through the log, as well as
+    /// [spout_message_box](Self::spout_message_box), are not affected.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn suppress_message_boxes(&mut self, suppress: bool) -> Result<()> {
+        let lib = unsafe { library!(self.library) };
+
+        let level = if suppress {
+            SpoutLibLogLevel::SPOUT_LOG_NONE
+        } else {
+            self.log_level.unwrap_or(SpoutLibLogLevel::SPOUT_LOG_NOTICE)
+        };
+
         lib.SetSpoutLogLevel(level);
+        self.message_boxes_suppressed = suppress;
 
         Ok(())
     }
@@ -1176,6 +3322,50 @@ impl RustySpout {
         Ok(lib.IsLaptop())
     }
 
+    /// Get the integrated and discrete adapter indices on a laptop, or `None` on a desktop.
+    ///
+    /// The adapters are found by matching the names of the Windows preferred adapters for
+    /// [DxgiGpuPreference::MinimumPower] and [DxgiGpuPreference::HighPerformance] against
+    /// the adapter names from [get_adapter_name](Self::get_adapter_name).
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn laptop_info(&mut self) -> Result<Option<LaptopInfo>> {
+        if !self.is_laptop()? {
+            return Ok(None);
+        }
+
+        let adapters = self.adapters()?;
+
+        Ok(Some(LaptopInfo {
+            integrated: self.preferred_adapter_index(&adapters, DxgiGpuPreference::MinimumPower)?,
+            discrete: self
+                .preferred_adapter_index(&adapters, DxgiGpuPreference::HighPerformance)?,
+        }))
+    }
+
+    /// Find the index of the Windows preferred adapter for `preference` in `adapters`.
+    fn preferred_adapter_index(
+        &mut self,
+        adapters: &[AdapterInfo],
+        preference: DxgiGpuPreference,
+    ) -> Result<Option<i32>> {
+        let (found, name) = self.get_preferred_adapter_name::<&str>(preference, 256)?;
+        if !found {
+            return Ok(None);
+        }
+
+        Ok(adapters
+            .iter()
+            .find(|adapter| adapter.name == name)
+            .map(|adapter| adapter.index))
+    }
+
+    /// Start a timing measurement. The measurement is finished with [end_timing](Self::end_timing)
+    /// or [end_timing_duration](Self::end_timing_duration).
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
     pub fn start_timing(&mut self) -> Result<()> {
         let lib = unsafe { library!(self.library) };
 
@@ -1184,36 +3374,105 @@ impl RustySpout {
         Ok(())
     }
 
+    /// Stop a timing measurement and return the elapsed time in **microseconds**.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
     pub fn end_timing(&mut self) -> Result<f64> {
         let lib = unsafe { library!(self.library) };
 
         Ok(lib.EndTiming())
     }
 
+    /// Stop a timing measurement and return the elapsed time as a [Duration].
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn end_timing_duration(&mut self) -> Result<Duration> {
+        let micros = self.end_timing()?;
+
+        match Duration::try_from_secs_f64(micros / 1_000_000.0) {
+            Ok(v) => Ok(v),
+            Err(e) => Err(Error::UnexpectedValue {
+                context: format!("end_timing_duration: {e}"),
+            }),
+        }
+    }
+
     pub fn is_initialized(&mut self) -> Result<bool> {
         let lib = unsafe { library!(self.library) };
 
         Ok(lib.IsInitialized())
     }
 
+    /// Bind the shared texture of the connected sender for use with OpenGL.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// An [Error::UnexpectedValue] is returned if Spout is not ready, unless
+    /// [assume_ready](Self::assume_ready) is set.
     pub fn bind_shared_texture(&mut self) -> Result<bool> {
+        self.ensure_ready("bind_shared_texture")?;
+
         let lib = unsafe { library!(self.library) };
 
         Ok(lib.BindSharedTexture())
     }
 
+    /// Unbind the shared texture bound with [bind_shared_texture](Self::bind_shared_texture).
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// An [Error::UnexpectedValue] is returned if Spout is not ready, unless
+    /// [assume_ready](Self::assume_ready) is set.
     pub fn unbind_shared_texture(&mut self) -> Result<bool> {
+        self.ensure_ready("unbind_shared_texture")?;
+
         let lib = unsafe { library!(self.library) };
 
         Ok(lib.UnBindSharedTexture())
     }
 
+    /// Get the OpenGL ID of the shared texture of the connected sender.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// An [Error::UnexpectedValue] is returned if Spout is not ready, unless
+    /// [assume_ready](Self::assume_ready) is set.
     pub fn get_shared_texture_id(&mut self) -> Result<GLuint> {
+        self.ensure_ready("get_shared_texture_id")?;
+
         let lib = unsafe { library!(self.library) };
 
         Ok(lib.GetSharedTextureID())
     }
 
+    /// Get the shared texture of the connected sender along with its target, dimensions, and
+    /// format. For use when receiving.
+    ///
+    /// Meant for handing the texture to engines and libraries that import external textures. The
+    /// texture is only valid while it is bound with [bind_shared_texture](Self::bind_shared_texture)
+    /// and the sender does not change size, so check [is_updated](Self::is_updated) before reusing
+    /// a descriptor.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// An [Error::UnexpectedValue] is returned if Spout is not ready, unless
+    /// [assume_ready](Self::assume_ready) is set.
+    pub fn shared_texture_descriptor(&mut self) -> Result<SharedTextureDescriptor> {
+        Ok(SharedTextureDescriptor {
+            texture_id: self.get_shared_texture_id()?,
+            target: GL_TEXTURE_2D,
+            width: self.get_sender_width()?,
+            height: self.get_sender_height()?,
+            format: self.get_sender_format()?,
+        })
+    }
+
     pub fn get_sender_count(&mut self) -> Result<i32> {
         let lib = unsafe { library!(self.library) };
 
@@ -1227,25 +3486,213 @@ impl RustySpout {
     ) -> Result<(bool, String)> {
         let lib = unsafe { library!(self.library) };
 
-        let mut buffer = vec![1; max_size - 1];
-        buffer.push(0);
-        let sender_name = buf_to_cstr!(buffer);
+        let mut buffer = vec![0_u8; max_size];
 
         let max_size = usize_to_c_int!(max_size);
 
-        let success = unsafe {
-            lib.GetSender(
-                index.into(),
-                sender_name.as_ptr().cast_mut(),
-                max_size.into(),
-            )
-        };
+        let success =
+            unsafe { lib.GetSender(index.into(), buffer.as_mut_ptr().cast(), max_size.into()) };
 
-        let sender_name = cstring_to_string!("get_sender", sender_name);
+        let sender_name = buf_to_string!("get_sender", buffer);
 
         Ok((success, sender_name))
     }
 
+    /// Get the name of the sender at `index` in the sender list.
+    ///
+    /// Returns `None` if the slot is empty. An [Error::UnexpectedValue] is returned if `index`
+    /// is not less than [get_sender_count](Self::get_sender_count).
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn get_sender_at(&mut self, index: usize) -> Result<Option<String>> {
+        let count = self.get_sender_count()?;
+        let index = match i32::try_from(index) {
+            Ok(v) if v < count => v,
+            _ => {
+                return Err(Error::UnexpectedValue {
+                    context: format!(
+                        "get_sender_at: index {index} out of range for {count} senders"
+                    ),
+                })
+            }
+        };
+
+        let (success, name) = self.get_sender::<&str>(index, MAX_SENDER_NAME_LEN)?;
+
+        Ok(if success { Some(name) } else { None })
+    }
+
+    /// Get the names of all senders on the system.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn senders(&mut self) -> Result<Vec<String>> {
+        let count = self.get_sender_count()?;
+
+        let mut senders = vec![];
+        for index in 0..count {
+            let (success, name) = self.get_sender::<&str>(index, MAX_SENDER_NAME_LEN)?;
+            if success {
+                senders.push(name);
+            }
+        }
+
+        Ok(senders)
+    }
+
+    /// Get the names of all senders on the system that start with `prefix`.
+    ///
+    /// The comparison is case-sensitive, like Spout's sender names.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn senders_with_prefix(&mut self, prefix: &str) -> Result<Vec<String>> {
+        let mut senders = self.senders()?;
+        senders.retain(|name| name.starts_with(prefix));
+
+        Ok(senders)
+    }
+
+    /// Get the senders that appeared or closed since the last call.
+    ///
+    /// The list from [senders](Self::senders) is stored for the next call, so the first call
+    /// returns every sender as added. Both lists keep the order of [senders](Self::senders).
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn sender_diff(&mut self) -> Result<SenderDiff> {
+        let senders = self.senders()?;
+
+        let diff = SenderDiff {
+            added: senders
+                .iter()
+                .filter(|name| !self.known_senders.contains(name))
+                .cloned()
+                .collect(),
+            removed: self
+                .known_senders
+                .iter()
+                .filter(|name| !senders.contains(name))
+                .cloned()
+                .collect(),
+        };
+        self.known_senders = senders;
+
+        Ok(diff)
+    }
+
+    /// Get the names and info of all senders on the system.
+    ///
+    /// Equivalent to calling [get_sender_info](Self::get_sender_info) for every name from
+    /// [senders](Self::senders), but the library is only looked up once and the
+    /// [cache](Self::set_sender_info_cache_ttl) is not used. Senders that close while listing, or
+    /// whose name is not valid UTF-8, are skipped.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// No safety guarantees are made about the returned `HANDLE`s.
+    pub fn senders_with_info(&mut self) -> Result<Vec<(String, SenderInfo)>> {
+        let mut lib = unsafe { library!(self.library) };
+
+        let count = lib.as_mut().GetSenderCount().0;
+        let max_size = usize_to_c_int!(MAX_SENDER_NAME_LEN);
+
+        let mut senders = vec![];
+        let mut buffer = vec![0_u8; MAX_SENDER_NAME_LEN];
+        for index in 0..count {
+            buffer.fill(0);
+            let found = unsafe {
+                lib.as_mut()
+                    .GetSender(index.into(), buffer.as_mut_ptr().cast(), max_size.into())
+            };
+            if !found {
+                continue;
+            }
+
+            let Ok(name) = CStr::from_bytes_until_nul(&buffer) else {
+                continue;
+            };
+
+            let mut width = c_uint(0);
+            let mut height = c_uint(0);
+            let mut share_handle: HANDLE = std::ptr::null_mut();
+            let mut format = c_ulong(0);
+
+            let found = unsafe {
+                lib.as_mut().GetSenderInfo(
+                    name.as_ptr(),
+                    &mut width,
+                    &mut height,
+                    &mut share_handle,
+                    &mut format,
+                )
+            };
+            if !found {
+                continue;
+            }
+
+            let Ok(name) = name.to_str() else {
+                continue;
+            };
+            senders.push((
+                name.to_string(),
+                SenderInfo {
+                    width: width.0,
+                    height: height.0,
+                    share_handle,
+                    format,
+                },
+            ));
+        }
+
+        Ok(senders)
+    }
+
+    /// Pick a sender from a text prompt without SpoutPanel.
+    ///
+    /// The senders from [senders](Self::senders) are written to `output` as a numbered list and
+    /// a number is read as a line from `input`. Returns `None` if there are no senders or if the
+    /// line is empty. The picked name can be passed to
+    /// [set_receiver_name](Self::set_receiver_name). See the `pick_sender` example for a console
+    /// picker using stdin and stdout.
+    ///
+    /// Applications that draw their own picker should use [senders](Self::senders) directly.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn pick_sender_blocking(
+        &mut self,
+        mut input: impl std::io::BufRead,
+        mut output: impl std::io::Write,
+    ) -> Result<Option<String>> {
+        let mut senders = self.senders()?;
+        if senders.is_empty() {
+            return Ok(None);
+        }
+
+        for (index, name) in senders.iter().enumerate() {
+            writeln!(output, "{index}: {name}")?;
+        }
+        output.flush()?;
+
+        let mut line = String::new();
+        input.read_line(&mut line)?;
+
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(None);
+        }
+
+        match line.parse::<usize>() {
+            Ok(v) if v < senders.len() => Ok(Some(senders.swap_remove(v))),
+            _ => Err(Error::UnexpectedValue {
+                context: format!("pick_sender_blocking: invalid selection {line}"),
+            }),
+        }
+    }
+
     pub fn find_sender_name<T: AsRef<str>>(&mut self, sender_name: T) -> Result<bool> {
         let lib = unsafe { library!(self.library) };
 
@@ -1256,49 +3703,150 @@ impl RustySpout {
         Ok(found)
     }
 
-    pub fn get_sender_info<T: AsRef<str>>(
-        &mut self,
-        sender_name: T,
-        width: u32,
-        height: u32,
-        share_handle: HANDLE,
-        format: DWORD,
-    ) -> Result<bool> {
+    /// Cache the results of [get_sender_info](Self::get_sender_info) for `ttl`.
+    ///
+    /// Off by default. Results are cached per sender name, and the whole cache is cleared when
+    /// [is_updated](Self::is_updated) reports a change. A `ttl` of zero turns caching back off.
+    pub fn set_sender_info_cache_ttl(&mut self, ttl: Duration) {
+        self.sender_info_cache.clear();
+        self.sender_info_cache_ttl = if ttl.is_zero() { None } else { Some(ttl) };
+    }
+
+    /// Get information about a named sender.
+    ///
+    /// Returns `None` if the sender does not exist. The result may be up to the
+    /// [cache ttl](Self::set_sender_info_cache_ttl) old if caching is enabled.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// No safety guarantees are made about the returned `HANDLE`.
+    pub fn get_sender_info<T: AsRef<str>>(&mut self, sender_name: T) -> Result<Option<SenderInfo>> {
+        let Some(ttl) = self.sender_info_cache_ttl else {
+            return self.query_sender_info(sender_name.as_ref());
+        };
+
+        if let Some((queried, info)) = self.sender_info_cache.get(sender_name.as_ref()) {
+            if queried.elapsed() < ttl {
+                return Ok(*info);
+            }
+        }
+
+        let info = self.query_sender_info(sender_name.as_ref())?;
+        self.sender_info_cache
+            .insert(sender_name.as_ref().to_string(), (Instant::now(), info));
+
+        Ok(info)
+    }
+
+    /// Get information about a named sender from Spout, bypassing the cache.
+    fn query_sender_info(&mut self, sender_name: &str) -> Result<Option<SenderInfo>> {
         let lib = unsafe { library!(self.library) };
 
         let sender_name = str_to_cstring!("get_sender_info", sender_name);
 
-        // TODO all these params need to be pinned
+        let mut width = c_uint(0);
+        let mut height = c_uint(0);
+        let mut share_handle: HANDLE = std::ptr::null_mut();
+        let mut format = c_ulong(0);
 
-        // let success = unsafe {
-        //     lib.GetSenderInfo(
-        //         sender_name.as_ptr(),
-        //         width.into(),
-        //         height.into(),
-        //         share_handle,
-        //         format,
-        //     )
-        // };
+        let found = unsafe {
+            lib.GetSenderInfo(
+                sender_name.as_ptr(),
+                &mut width,
+                &mut height,
+                &mut share_handle,
+                &mut format,
+            )
+        };
 
-        // Ok(success)
+        if !found {
+            return Ok(None);
+        }
 
-        todo!()
+        Ok(Some(SenderInfo {
+            width: width.0,
+            height: height.0,
+            share_handle,
+            format,
+        }))
+    }
+
+    /// Read the current dimensions and format of a named sender without receiving from it.
+    ///
+    /// Spout reads sender info straight from the sender's shared memory, so unlike
+    /// [create_receiver](Self::create_receiver) nothing is connected, no texture is opened, and
+    /// there is nothing to release afterwards. The [cache](Self::set_sender_info_cache_ttl) is
+    /// bypassed so the result is always current. Returns `None` if the sender does not exist.
+    ///
+    /// The sender's fps is not part of its shared info and is only available while receiving,
+    /// see [get_sender_fps](Self::get_sender_fps).
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// No safety guarantees are made about the returned `HANDLE`.
+    pub fn probe_sender(&mut self, name: &str) -> Result<Option<SenderInfo>> {
+        self.query_sender_info(name)
+    }
+
+    /// Check if two senders share textures in compatible formats, meaning that frames can be
+    /// relayed from one to the other without a conversion pass.
+    ///
+    /// The Spout default format and `DXGI_FORMAT_B8G8R8X8_UNORM` are treated as the same as
+    /// `DXGI_FORMAT_B8G8R8A8_UNORM`, and formats of the same `_TYPELESS` family that only differ
+    /// in sRGB are compatible. An [Error::UnexpectedValue] is returned if either sender does not
+    /// exist.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn formats_compatible(&mut self, sender_a: &str, sender_b: &str) -> Result<bool> {
+        let mut sender_format = |name: &str| -> Result<DWORD> {
+            match self.get_sender_info(name)? {
+                Some(v) => Ok(v.format),
+                None => Err(Error::UnexpectedValue {
+                    context: format!("formats_compatible: sender {name} not found"),
+                }),
+            }
+        };
+
+        let a = sender_format(sender_a)?;
+        let b = sender_format(sender_b)?;
+
+        Ok(dxgi_formats_compatible(a, b))
     }
 
     pub fn get_active_sender<T: AsRef<str>>(&mut self) -> Result<(bool, String)> {
         let lib = unsafe { library!(self.library) };
 
-        let mut buffer = vec![];
-        buffer.push(0);
-        let sender_name = buf_to_cstr!(buffer);
+        // Spout copies up to the maximum sender name length into the buffer
+        let mut buffer = vec![0_u8; MAX_SENDER_NAME_LEN];
 
-        let success = unsafe { lib.GetActiveSender(sender_name.as_ptr().cast_mut()) };
+        let success = unsafe { lib.GetActiveSender(buffer.as_mut_ptr().cast()) };
 
-        let sender_name = cstring_to_string!("get_active_sender", sender_name);
+        let sender_name = buf_to_string!("get_active_sender", buffer);
 
         Ok((success, sender_name))
     }
 
+    /// Check if this process's sender is the active sender.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn is_active_sender(&mut self) -> Result<bool> {
+        let name = match &self.sender_name {
+            Some(v) => v.clone(),
+            None => self.get_name()?,
+        };
+        if name.is_empty() {
+            return Ok(false);
+        }
+
+        let (found, active_name) = self.get_active_sender::<&str>()?;
+
+        Ok(found && name == active_name)
+    }
+
     pub fn set_active_sender<T: AsRef<str>>(&mut self, sender_name: T) -> Result<bool> {
         let lib = unsafe { library!(self.library) };
 
@@ -1329,7 +3877,23 @@ impl RustySpout {
         Ok(lib.GetBuffers().0)
     }
 
+    /// Set the number of buffers used for CPU sharing.
+    ///
+    /// Must be in the range [MIN_BUFFERS]..=[MAX_BUFFERS]. Spout does not check this itself, and
+    /// values outside of the range corrupt frames in the CPU sharing path. The range is checked
+    /// before Spout is called.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
     pub fn set_buffers(&mut self, buffers: i32) -> Result<()> {
+        if !(MIN_BUFFERS..=MAX_BUFFERS).contains(&buffers) {
+            return Err(Error::UnexpectedValue {
+                context: format!(
+                    "set_buffers: {buffers} is not in the range {MIN_BUFFERS}..={MAX_BUFFERS}"
+                ),
+            });
+        }
+
         let lib = unsafe { library!(self.library) };
 
         lib.SetBuffers(buffers.into());
@@ -1343,7 +3907,23 @@ impl RustySpout {
         Ok(lib.GetMaxSenders().0)
     }
 
+    /// Set the maximum number of senders allowed on the system.
+    ///
+    /// Must be in the range [MIN_MAX_SENDERS]..=[MAX_MAX_SENDERS]. Spout does not check this
+    /// itself, and `0` prevents any sender from being registered. The range is checked before
+    /// Spout is called.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
     pub fn set_max_senders(&mut self, max_senders: i32) -> Result<()> {
+        if !(MIN_MAX_SENDERS..=MAX_MAX_SENDERS).contains(&max_senders) {
+            return Err(Error::UnexpectedValue {
+                context: format!(
+                    "set_max_senders: {max_senders} is not in the range {MIN_MAX_SENDERS}..={MAX_MAX_SENDERS}"
+                ),
+            });
+        }
+
         let lib = unsafe { library!(self.library) };
 
         lib.SetMaxSenders(max_senders.into());
@@ -1351,6 +3931,15 @@ impl RustySpout {
         Ok(())
     }
 
+    /// Create a sender.
+    ///
+    /// `format` must be `0` to use the Spout default or one of [supported_sender_formats].
+    ///
+    /// The resulting name is cached for [sender_name](Self::sender_name) as with
+    /// [set_sender_name](Self::set_sender_name).
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
     pub fn create_sender<T: AsRef<str>>(
         &mut self,
         sender_name: T,
@@ -1360,14 +3949,56 @@ impl RustySpout {
     ) -> Result<bool> {
         let lib = unsafe { library!(self.library) };
 
-        let sender_name = str_to_cstring!("create_sender", sender_name);
+        if format.0 != 0 && DxgiFormat::try_from(format).is_err() {
+            return Err(Error::UnexpectedValue {
+                context: format!("create_sender: unsupported format {}", format.0),
+            });
+        }
+
+        let name = sender_name.as_ref();
+        let c_name = str_to_cstring!("create_sender", sender_name);
 
         let success =
-            unsafe { lib.CreateSender(sender_name.as_ptr(), width.into(), height.into(), format) };
+            unsafe { lib.CreateSender(c_name.as_ptr(), width.into(), height.into(), format) };
+        if success {
+            self.role = Some(Role::Sender);
+            self.sender_name = Some(self.get_name().unwrap_or_else(|_| name.to_owned()));
+        }
 
         Ok(success)
     }
 
+    /// Create a sender with the given name in a single call, returning a [Sender] view of it.
+    ///
+    /// Unlike [set_sender_name](Self::set_sender_name) followed by a send, the name is passed
+    /// straight to `CreateSender`, so there is no window where the sender exists under a default
+    /// or previous name. The sender is added to the list counted by
+    /// [get_sender_count](Self::get_sender_count) exactly once, already under `name`.
+    ///
+    /// An [Error::UnexpectedValue] is returned if Spout refuses to create the sender, e.g. if
+    /// another sender already registered `name` in the meantime. Use
+    /// [Sender::release_on_drop] to close the sender again when the view goes away.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn create_sender_atomic<T: AsRef<str>>(
+        &mut self,
+        sender_name: T,
+        width: u32,
+        height: u32,
+        format: DWORD,
+    ) -> Result<Sender<'_>> {
+        let sender_name = sender_name.as_ref();
+
+        if !self.create_sender(sender_name, width, height, format)? {
+            return Err(Error::UnexpectedValue {
+                context: format!("create_sender_atomic: unable to create sender {sender_name}"),
+            });
+        }
+
+        Ok(self.as_sender())
+    }
+
     pub fn update_sender<T: AsRef<str>>(
         &mut self,
         sender_name: T,
@@ -1384,19 +4015,75 @@ impl RustySpout {
         Ok(success)
     }
 
+    /// Create a sender backed by an existing shared texture.
+    ///
+    /// The texture behind `share_handle` would remain owned by the caller and must match
+    /// `width`, `height`, and `format` exactly.
+    ///
+    /// # Important
+    /// This method is not bindable to Rust. `SPOUTLIBRARY` always allocates its own shared texture
+    /// and only the lower-level `spoutSenderNames::CreateSender` accepts a share handle, which is
+    /// not exposed by `SpoutLibrary`.
+    pub fn create_sender_from_handle<T: AsRef<str>>(
+        &mut self,
+        _sender_name: T,
+        _width: u32,
+        _height: u32,
+        _share_handle: HANDLE,
+        _format: DWORD,
+    ) -> Result<bool> {
+        Err(Error::Unbindable)
+    }
+
     /// Create receiver connection.
     ///
+    /// An empty `sender_name` (or `use_active`) connects to the active sender. Returns the name
+    /// and dimensions of the sender that was connected to, or `None` if no connection was made.
+    ///
     /// # Safety
     /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// Unlike [set_receiver_name](Self::set_receiver_name), the name is an in/out buffer that Spout
+    /// writes the connected sender name into, so an empty name is passed as an empty string
+    /// rather than a `nullptr`.
     pub fn create_receiver<T: AsRef<str>>(
         &mut self,
         sender_name: T,
-        width: u32,
-        height: u32,
         use_active: bool,
-    ) -> Result<bool> {
-        // TODO this method requires all params to be pinned
-        todo!()
+    ) -> Result<Option<(String, u32, u32)>> {
+        let lib = unsafe { library!(self.library) };
+
+        let sender_name = sender_name.as_ref().as_bytes();
+        if sender_name.len() >= MAX_SENDER_NAME_LEN || sender_name.contains(&0) {
+            return Err(Error::FfiTypeInto {
+                ffi_type: FfiType::CString,
+                context: "create_receiver: invalid sender name".to_string(),
+            });
+        }
+
+        let mut buffer = vec![0_u8; MAX_SENDER_NAME_LEN];
+        buffer[..sender_name.len()].copy_from_slice(sender_name);
+
+        let mut width = c_uint(0);
+        let mut height = c_uint(0);
+
+        let success = unsafe {
+            lib.CreateReceiver(
+                buffer.as_mut_ptr().cast(),
+                &mut width,
+                &mut height,
+                use_active,
+            )
+        };
+
+        if !success {
+            return Ok(None);
+        }
+        self.role = Some(Role::Receiver);
+
+        let sender_name = buf_to_string!("create_receiver", buffer);
+
+        Ok(Some((sender_name, width.0, height.0)))
     }
 
     /// Check receiver connection.
@@ -1420,10 +4107,25 @@ impl RustySpout {
         Ok(lib.GetDX9())
     }
 
+    /// Select DirectX 9 instead of DirectX 11 for sharing.
+    ///
+    /// Returns `true` if the change took effect and `false` if Spout rejected it. Spout 2.007
+    /// dropped DirectX 9, so enabling it is reported as [Error::UnexpectedValue] rather than a
+    /// bare `false`.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
     pub fn set_dx9(&mut self, dx9: bool) -> Result<bool> {
         let lib = unsafe { library!(self.library) };
 
-        Ok(lib.SetDX9(dx9))
+        let success = lib.SetDX9(dx9);
+        if !success && dx9 {
+            return Err(Error::UnexpectedValue {
+                context: "set_dx9: DirectX 9 is not supported by this version of Spout".to_string(),
+            });
+        }
+
+        Ok(success)
     }
 
     pub fn get_memory_share_mode(&mut self) -> Result<bool> {
@@ -1432,10 +4134,23 @@ impl RustySpout {
         Ok(lib.GetMemoryShareMode())
     }
 
+    /// Share frames through a shared memory map instead of GPU textures.
+    ///
+    /// Returns `true` if the change took effect and `false` if Spout rejected it. Spout only
+    /// checks the flag when a sender or receiver is created, so changing it afterwards is
+    /// reported as [Error::UnexpectedValue] rather than a bare `false`.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
     pub fn set_memory_share_mode(&mut self, mem: bool) -> Result<bool> {
         let lib = unsafe { library!(self.library) };
 
-        Ok(lib.SetMemoryShareMode(mem))
+        let success = lib.SetMemoryShareMode(mem);
+        if !success {
+            self.ensure_not_connected("set_memory_share_mode")?;
+        }
+
+        Ok(success)
     }
 
     pub fn get_cpu_mode(&mut self) -> Result<bool> {
@@ -1444,10 +4159,26 @@ impl RustySpout {
         Ok(lib.GetCPUmode())
     }
 
+    /// Copy frames through the CPU using DirectX textures instead of GL/DX interop.
+    ///
+    /// Returns `true` if the change took effect and `false` if Spout rejected it. CPU sharing
+    /// cannot be turned off without GL/DX interop (see [is_gl_dx_ready](Self::is_gl_dx_ready)),
+    /// so that case is reported as [Error::UnexpectedValue] rather than a bare `false`.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
     pub fn set_cpu_mode(&mut self, cpu: bool) -> Result<bool> {
         let lib = unsafe { library!(self.library) };
 
-        Ok(lib.SetCPUmode(cpu))
+        let success = lib.SetCPUmode(cpu);
+        if !success && !cpu && !self.is_gl_dx_ready()? {
+            return Err(Error::UnexpectedValue {
+                context: "set_cpu_mode: GL/DX interop is not available, so CPU sharing is required"
+                    .to_string(),
+            });
+        }
+
+        Ok(success)
     }
 
     pub fn get_share_mode(&mut self) -> Result<i32> {
@@ -1464,9 +4195,140 @@ impl RustySpout {
         Ok(())
     }
 
+    /// Read the current [GlobalSettings].
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn get_global_settings(&mut self) -> Result<GlobalSettings> {
+        Ok(GlobalSettings {
+            buffer_mode: self.get_buffer_mode()?,
+            buffers: self.get_buffers()?,
+            max_senders: self.get_max_senders()?,
+            share_mode: ShareMode::try_from(self.get_share_mode()?)?,
+            auto_share: self.get_auto_share()?,
+            frame_count: self.is_frame_count_enabled()?,
+        })
+    }
+
+    /// Apply all values from a [GlobalSettings].
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn apply_global_settings(&mut self, settings: &GlobalSettings) -> Result<()> {
+        self.set_buffer_mode(settings.buffer_mode)?;
+        self.set_buffers(settings.buffers)?;
+        self.set_max_senders(settings.max_senders)?;
+        self.set_share_mode(settings.share_mode.into())?;
+        self.set_auto_share(settings.auto_share)?;
+        self.set_frame_count(settings.frame_count)?;
+
+        Ok(())
+    }
+
+    /// Set the [SharingPath] by setting the low-level sharing flags consistently.
+    ///
+    /// | Path         | `set_auto_share` | `set_memory_share_mode` | `set_cpu_mode` |
+    /// |--------------|------------------|-------------------------|----------------|
+    /// | `Auto`       | `true`           | `false`                 | `false`        |
+    /// | `GpuTexture` | `false`          | `false`                 | `false`        |
+    /// | `GpuMemory`  | `false`          | `true`                  | not called     |
+    /// | `Cpu`        | `false`          | `false`                 | `true`         |
+    ///
+    /// The calls are made in the order of the columns. An [Error] is returned if Spout rejects
+    /// either mode change. CPU mode is left alone for `GpuMemory`, since memory sharing takes
+    /// precedence over it and turning it off fails without GL/DX interop, which is exactly when
+    /// memory sharing is needed.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn set_sharing_path(&mut self, path: SharingPath) -> Result<()> {
+        let (auto, memory, cpu) = match path {
+            SharingPath::Auto => (true, false, Some(false)),
+            SharingPath::GpuTexture => (false, false, Some(false)),
+            SharingPath::GpuMemory => (false, true, None),
+            SharingPath::Cpu => (false, false, Some(true)),
+        };
+
+        self.set_auto_share(auto)?;
+        if !self.set_memory_share_mode(memory)? {
+            return Err(Error::UnexpectedValue {
+                context: format!("set_sharing_path: SetMemoryShareMode({memory}) failed"),
+            });
+        }
+        if let Some(cpu) = cpu {
+            if !self.set_cpu_mode(cpu)? {
+                return Err(Error::UnexpectedValue {
+                    context: format!("set_sharing_path: SetCPUmode({cpu}) failed"),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Always share GPU textures, disabling the automatic CPU fallback.
+    ///
+    /// Shorthand for [set_sharing_path](Self::set_sharing_path) with [SharingPath::GpuTexture].
+    /// Must be called before the sender or receiver is created, since Spout only checks the flags
+    /// when connecting. Only this instance is affected, the system-wide
+    /// [share mode](Self::set_share_mode) is left alone.
+    ///
+    /// An [Error::UnexpectedValue] is returned if this is already sending or receiving.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn force_texture_share(&mut self) -> Result<()> {
+        self.ensure_not_connected("force_texture_share")?;
+
+        self.set_sharing_path(SharingPath::GpuTexture)
+    }
+
+    /// Share frames through a shared memory map instead of GPU textures.
+    ///
+    /// Shorthand for [set_sharing_path](Self::set_sharing_path) with [SharingPath::GpuMemory].
+    /// This works without GL/DX interop, e.g. in a virtual machine without a GPU, but both sides
+    /// must use it. Must be called before the sender or receiver is created, since Spout only
+    /// checks the flags when connecting. Only this instance is affected, the system-wide
+    /// [share mode](Self::set_share_mode) is left alone.
+    ///
+    /// An [Error::UnexpectedValue] is returned if this is already sending or receiving.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn force_memory_share(&mut self) -> Result<()> {
+        self.ensure_not_connected("force_memory_share")?;
+
+        self.set_sharing_path(SharingPath::GpuMemory)
+    }
+
+    /// Return an [Error::UnexpectedValue] if a sender or receiver has already been created.
+    fn ensure_not_connected(&self, context: &str) -> Result<()> {
+        match self.role {
+            Some(role) => Err(Error::UnexpectedValue {
+                context: format!(
+                    "{context}: already a {role:?}, call this before sending or receiving"
+                ),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Open SpoutPanel to select a sender.
+    ///
+    /// Returns an [Error] if SpoutPanel is not installed (see [is_spout_installed]), since Spout
+    /// silently does nothing in that case.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
     pub fn select_sender_panel(&mut self) -> Result<()> {
         let lib = unsafe { library!(self.library) };
 
+        if registry::spout_panel_path()?.is_none() {
+            return Err(Error::UnexpectedValue {
+                context: "SpoutPanel not installed".to_string(),
+            });
+        }
+
         lib.SelectSenderPanel();
 
         Ok(())
@@ -1516,6 +4378,56 @@ impl RustySpout {
         Ok(lib.GetSpoutVersion().0)
     }
 
+    /// Get the crate, installed Spout, and `SpoutLibrary.dll` versions at once.
+    pub fn versions(&mut self) -> Result<Versions> {
+        Ok(Versions {
+            crate_version: env!("CARGO_PKG_VERSION"),
+            spout_version: self.get_spout_version()?,
+            sdk_version: self.get_sdk_version()?,
+        })
+    }
+
+    /// Compare the loaded Spout library and the installed Spout against
+    /// [SPOUT_BUILT_VERSION].
+    ///
+    /// The library version from [get_sdk_version](Self::get_sdk_version) is compared first,
+    /// including the patch level, since a different `SpoutLibrary.dll` than the one the crate was
+    /// built against can fail in subtle ways. If it matches, the installed version from
+    /// [get_spout_version](Self::get_spout_version) is compared by release, e.g. 2.007, and is
+    /// skipped if Spout is not installed.
+    ///
+    /// An [Error::UnexpectedValue] is returned if the library version cannot be parsed.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn check_version_compatibility(&mut self) -> Result<VersionCompat> {
+        let parse = |version: &str| {
+            parse_spout_version(version).ok_or_else(|| Error::UnexpectedValue {
+                context: format!("check_version_compatibility: unable to parse {version:?}"),
+            })
+        };
+
+        let built = parse(SPOUT_BUILT_VERSION)?;
+        let sdk_version = self.get_sdk_version()?;
+        let sdk = parse(sdk_version.as_str())?;
+        let spout_version = self.get_spout_version()?;
+
+        Ok(match compare_spout_versions(built, sdk, spout_version) {
+            std::cmp::Ordering::Less => VersionCompat::Older {
+                sdk_version,
+                spout_version,
+            },
+            std::cmp::Ordering::Equal => VersionCompat::Compatible {
+                sdk_version,
+                spout_version,
+            },
+            std::cmp::Ordering::Greater => VersionCompat::Newer {
+                sdk_version,
+                spout_version,
+            },
+        })
+    }
+
     pub fn get_auto_share(&mut self) -> Result<bool> {
         let lib = unsafe { library!(self.library) };
 
@@ -1536,6 +4448,49 @@ impl RustySpout {
         Ok(lib.IsGLDXready())
     }
 
+    /// Block until [is_gl_dx_ready](Self::is_gl_dx_ready) is `true`, polling every few
+    /// milliseconds.
+    ///
+    /// Spout may finish setting up its DirectX device shortly after [get_spout](Self::get_spout),
+    /// so sending immediately can fail. Returns `false` if GL/DX interop never became ready
+    /// within `timeout`, in which case Spout will fall back to CPU sharing.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn wait_until_ready(&mut self, timeout: Duration) -> Result<bool> {
+        const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+        let start = Instant::now();
+        loop {
+            if self.is_gl_dx_ready()? {
+                return Ok(true);
+            }
+
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                return Ok(false);
+            }
+            std::thread::sleep(POLL_INTERVAL.min(timeout - elapsed));
+        }
+    }
+
+    /// Check if this application's sender has fallen back from GPU texture sharing to a slower
+    /// path. For use when sending.
+    ///
+    /// Returns `true` if any of these hold:
+    /// * [is_gl_dx_ready](Self::is_gl_dx_ready) is `false`, so GL/DX interop is unavailable
+    /// * [get_gl_dx](Self::get_gl_dx) is `false`, so the sender is not using GL/DX interop
+    /// * [get_cpu](Self::get_cpu) is `true`, so frames are copied through the CPU
+    ///
+    /// Only meaningful once the sender has been created by a send. Use
+    /// [sender_sharing_mode](Self::sender_sharing_mode) to check the sender being received from.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn is_using_fallback(&mut self) -> Result<bool> {
+        Ok(!self.is_gl_dx_ready()? || !self.get_gl_dx()? || self.get_cpu()?)
+    }
+
     pub fn get_num_adapters(&mut self) -> Result<i32> {
         let lib = unsafe { library!(self.library) };
 
@@ -1549,21 +4504,15 @@ impl RustySpout {
     ) -> Result<(bool, String)> {
         let lib = unsafe { library!(self.library) };
 
-        let mut buffer = vec![1; max_chars - 1];
-        buffer.push(0);
-        let adapter_name = buf_to_cstr!(buffer);
+        let mut buffer = vec![0_u8; max_chars];
 
         let max_chars = usize_to_c_int!(max_chars);
 
         let success = unsafe {
-            lib.GetAdapterName(
-                index.into(),
-                adapter_name.as_ptr().cast_mut(),
-                max_chars.into(),
-            )
+            lib.GetAdapterName(index.into(), buffer.as_mut_ptr().cast(), max_chars.into())
         };
 
-        let adapter_name = cstring_to_string!("get_adapter_name", adapter_name);
+        let adapter_name = buf_to_string!("get_adapter_name", buffer);
 
         Ok((success, adapter_name))
     }
@@ -1574,6 +4523,82 @@ impl RustySpout {
         Ok(lib.GetAdapter().0)
     }
 
+    /// Get every adapter known to Spout.
+    ///
+    /// Adapters whose names cannot be read are skipped.
+    fn adapters(&mut self) -> Result<Vec<AdapterInfo>> {
+        let count = self.get_num_adapters()?;
+
+        let mut adapters = vec![];
+        for index in 0..count {
+            if let Ok((true, name)) = self.get_adapter_name::<&str>(index, 256) {
+                adapters.push(AdapterInfo { index, name });
+            }
+        }
+
+        Ok(adapters)
+    }
+
+    /// Get details about the adapter at `index`, as used by
+    /// [get_adapter_name](Self::get_adapter_name).
+    ///
+    /// Spout enumerates adapters with `IDXGIFactory1::EnumAdapters1`, so the DXGI adapter at the
+    /// same index is the same adapter. An [Error::UnexpectedValue] is returned if there is no
+    /// adapter at `index`.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn adapter_details(&mut self, index: i32) -> Result<AdapterDetails> {
+        let name = match self.get_adapter_name::<&str>(index, 256)? {
+            (true, v) => v,
+            (false, _) => {
+                return Err(Error::UnexpectedValue {
+                    context: format!("adapter_details: no adapter at index {index}"),
+                })
+            }
+        };
+
+        #[cfg(feature = "windows")]
+        {
+            use windows::Win32::Graphics::Dxgi::{CreateDXGIFactory1, IDXGIFactory1};
+
+            let dxgi_index = match u32::try_from(index) {
+                Ok(v) => v,
+                Err(e) => {
+                    return Err(Error::UnexpectedValue {
+                        context: format!("adapter_details: {e}"),
+                    })
+                }
+            };
+
+            let desc = unsafe {
+                CreateDXGIFactory1::<IDXGIFactory1>()
+                    .and_then(|factory| factory.EnumAdapters1(dxgi_index))
+                    .and_then(|adapter| adapter.GetDesc1())
+            }
+            .map_err(|e| Error::UnexpectedValue {
+                context: format!("adapter_details: {e}"),
+            })?;
+
+            let len = desc
+                .Description
+                .iter()
+                .position(|v| *v == 0)
+                .unwrap_or(desc.Description.len());
+
+            Ok(AdapterDetails {
+                index,
+                name,
+                description: String::from_utf16_lossy(&desc.Description[..len]),
+                dedicated_video_memory: desc.DedicatedVideoMemory as u64,
+                vendor_id: desc.VendorId,
+            })
+        }
+
+        #[cfg(not(feature = "windows"))]
+        Ok(AdapterDetails { index, name })
+    }
+
     pub fn get_performance_preference<T: AsRef<str>>(
         &mut self,
         path: T,
@@ -1587,6 +4612,20 @@ impl RustySpout {
         DxgiGpuPreference::try_from(val.0)
     }
 
+    /// [get_performance_preference](Self::get_performance_preference) for the current
+    /// executable.
+    ///
+    /// Returns [DxgiGpuPreference::NotRegistered] if no preference has been set. An
+    /// [Error::Io] is returned if the executable path cannot be found.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn get_performance_preference_self(&mut self) -> Result<DxgiGpuPreference> {
+        let path = current_exe_path()?;
+
+        self.get_performance_preference(path)
+    }
+
     pub fn set_performance_preference<T: AsRef<str>>(
         &mut self,
         preference: DxgiGpuPreference,
@@ -1606,6 +4645,21 @@ impl RustySpout {
         Ok(success)
     }
 
+    /// [set_performance_preference](Self::set_performance_preference) for the current executable.
+    ///
+    /// An [Error::Io] is returned if the executable path cannot be found.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn set_performance_preference_self(
+        &mut self,
+        preference: DxgiGpuPreference,
+    ) -> Result<bool> {
+        let path = current_exe_path()?;
+
+        self.set_performance_preference(preference, path)
+    }
+
     pub fn get_preferred_adapter_name<T: AsRef<str>>(
         &mut self,
         preference: DxgiGpuPreference,
@@ -1619,21 +4673,15 @@ impl RustySpout {
             Err(e) => return Err(e),
         };
 
-        let mut buffer = vec![1; max_chars - 1];
-        buffer.push(0);
-        let adapter_name = buf_to_cstr!(buffer);
+        let mut buffer = vec![0_u8; max_chars];
 
         let max_chars = usize_to_c_int!(max_chars);
 
         let success = unsafe {
-            lib.GetPreferredAdapterName(
-                preference,
-                adapter_name.as_ptr().cast_mut(),
-                max_chars.into(),
-            )
+            lib.GetPreferredAdapterName(preference, buffer.as_mut_ptr().cast(), max_chars.into())
         };
 
-        let adapter_name = cstring_to_string!("get_preferred_adapter_name", adapter_name);
+        let adapter_name = buf_to_string!("get_preferred_adapter_name", buffer);
 
         Ok((success, adapter_name))
     }
@@ -1666,6 +4714,37 @@ impl RustySpout {
         Ok(success)
     }
 
+    /// Make this application use the high performance GPU on laptops with switchable graphics.
+    ///
+    /// Registers [DxgiGpuPreference::HighPerformance] for the current executable with
+    /// [set_performance_preference](Self::set_performance_preference), which Windows applies
+    /// from the next launch, and selects the matching adapter for this run with
+    /// [set_preferred_adapter](Self::set_preferred_adapter). Call it before sending or receiving,
+    /// since the adapter is only used by senders and receivers created afterwards.
+    ///
+    /// Returns `false` without changing anything if preferences are not available (see
+    /// [is_preference_available](Self::is_preference_available)) or the executable is not a
+    /// valid application path, and `false` if either step fails.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn prefer_high_performance_gpu(&mut self) -> Result<bool> {
+        if !self.is_preference_available()? {
+            return Ok(false);
+        }
+
+        let path = current_exe_path()?;
+        if !self.is_application_path(&path)? {
+            return Ok(false);
+        }
+
+        if !self.set_performance_preference(DxgiGpuPreference::HighPerformance, &path)? {
+            return Ok(false);
+        }
+
+        self.set_preferred_adapter(DxgiGpuPreference::HighPerformance)
+    }
+
     pub fn create_opengl(&mut self) -> Result<bool> {
         let lib = unsafe { library!(self.library) };
 
@@ -1678,6 +4757,37 @@ impl RustySpout {
         Ok(lib.CloseOpenGL())
     }
 
+    /// Use the application's own OpenGL context instead of one created by Spout.
+    ///
+    /// Spout always works in whichever context is current on the calling thread, and only
+    /// creates its own when [create_opengl](Self::create_opengl) is called or when nothing is
+    /// current at the first send or receive. A second context can conflict with the
+    /// application's, so applications with their own context should make it current and call
+    /// this before the first send or receive, instead of [create_opengl](Self::create_opengl).
+    ///
+    /// Every later call into Spout must be made on the same thread with the same context
+    /// current, since OpenGL contexts are bound to a thread. If Spout's own context was created
+    /// earlier, close it with [close_opengl](Self::close_opengl) first.
+    ///
+    /// An [Error::UnexpectedValue] is returned if no context is current on this thread.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn use_existing_gl_context(&mut self) -> Result<()> {
+        if self.library.is_none() {
+            return Err(Error::NoHandle);
+        }
+
+        if unsafe { gl::wglGetCurrentContext() }.is_null() {
+            return Err(Error::UnexpectedValue {
+                context: "use_existing_gl_context: no OpenGL context is current on this thread"
+                    .to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
     pub fn copy_texture(
         &mut self,
         source_id: GLuint,
@@ -1703,6 +4813,259 @@ impl RustySpout {
         ))
     }
 
+    /// Copy one OpenGL texture to another while converting the pixel format.
+    ///
+    /// `CopyTexture` cannot convert formats, so this reads the source texture to the CPU,
+    /// reorders the channels, and uploads the result to the destination texture. This is much
+    /// slower than [copy_texture](Self::copy_texture) and should only be used when a format
+    /// conversion is needed. Both textures are unbound afterwards. An [Error::UnexpectedValue] is
+    /// returned if the source texture is not exactly `width` x `height`.
+    ///
+    /// # Safety
+    /// Requires a current OpenGL context on the calling thread, since the texture is read and
+    /// written with OpenGL directly. The pack and unpack alignments are set to 1 for the copy and
+    /// restored afterwards. The destination texture must be at least `width` x `height`.
+    ///
+    /// No safety guarantees can be made about the texture ids or targets.
+    #[allow(clippy::too_many_arguments)]
+    pub fn copy_texture_converted(
+        &mut self,
+        source_id: GLuint,
+        source_target: GLuint,
+        source_format: PixelFormat,
+        dest_id: GLuint,
+        dest_target: GLuint,
+        dest_format: PixelFormat,
+        width: u32,
+        height: u32,
+    ) -> Result<()> {
+        if self.library.is_none() {
+            return Err(Error::NoHandle);
+        }
+        if source_format != dest_format && (source_format.is_float() || dest_format.is_float()) {
+            return Err(Error::UnexpectedValue {
+                context: format!(
+                    "copy_texture_converted: cannot convert {source_format:?} to {dest_format:?}"
+                ),
+            });
+        }
+
+        let (gl_width, gl_height) = match (i32::try_from(width), i32::try_from(height)) {
+            (Ok(w), Ok(h)) => (w, h),
+            _ => {
+                return Err(Error::FfiTypeInto {
+                    ffi_type: FfiType::CInt,
+                    context: "copy_texture_converted: dimensions too large".to_string(),
+                })
+            }
+        };
+
+        unsafe {
+            gl::glBindTexture(source_target.0, source_id.0);
+        }
+
+        // glGetTexImage always reads the whole texture, so it must not be larger than the buffer
+        let (mut texture_width, mut texture_height) = (0, 0);
+        unsafe {
+            gl::glGetTexLevelParameteriv(
+                source_target.0,
+                0,
+                gl::GL_TEXTURE_WIDTH,
+                &mut texture_width,
+            );
+            gl::glGetTexLevelParameteriv(
+                source_target.0,
+                0,
+                gl::GL_TEXTURE_HEIGHT,
+                &mut texture_height,
+            );
+        }
+        if (texture_width, texture_height) != (gl_width, gl_height) {
+            unsafe {
+                gl::glBindTexture(source_target.0, 0);
+            }
+            return Err(Error::UnexpectedValue {
+                context: format!(
+                    "copy_texture_converted: source texture is {texture_width}x{texture_height}, \
+                    expected {width}x{height}"
+                ),
+            });
+        }
+
+        let (mut pack_alignment, mut unpack_alignment) = (4, 4);
+        unsafe {
+            gl::glGetIntegerv(gl::GL_PACK_ALIGNMENT, &mut pack_alignment);
+            gl::glGetIntegerv(gl::GL_UNPACK_ALIGNMENT, &mut unpack_alignment);
+            // The buffer rows are tightly packed, which the default alignment of 4 does not allow
+            // for 3-byte pixels
+            gl::glPixelStorei(gl::GL_PACK_ALIGNMENT, 1);
+            gl::glPixelStorei(gl::GL_UNPACK_ALIGNMENT, 1);
+        }
+
+        let mut pixels = vec![0_u8; source_format.buffer_size(width, height)];
+        unsafe {
+            gl::glGetTexImage(
+                source_target.0,
+                0,
+                source_format.unswizzled_gl_format().0,
+                source_format.gl_type(),
+                pixels.as_mut_ptr().cast(),
+            );
+            gl::glBindTexture(source_target.0, 0);
+        }
+
+        let pixels = if source_format == dest_format {
+            Ok(pixels)
+        } else {
+            let stride = source_format.buffer_size(width, 1);
+            convert::convert(&pixels, width, height, stride, source_format, dest_format)
+        };
+
+        if let Ok(pixels) = &pixels {
+            unsafe {
+                gl::glBindTexture(dest_target.0, dest_id.0);
+                gl::glTexSubImage2D(
+                    dest_target.0,
+                    0,
+                    0,
+                    0,
+                    gl_width,
+                    gl_height,
+                    dest_format.unswizzled_gl_format().0,
+                    dest_format.gl_type(),
+                    pixels.as_ptr().cast(),
+                );
+                gl::glBindTexture(dest_target.0, 0);
+            }
+        }
+
+        unsafe {
+            gl::glPixelStorei(gl::GL_PACK_ALIGNMENT, pack_alignment);
+            gl::glPixelStorei(gl::GL_UNPACK_ALIGNMENT, unpack_alignment);
+        }
+
+        pixels.map(|_| ())
+    }
+
+    /// Receive from the sender named `from` and send the same frame as a new sender named `to`.
+    ///
+    /// Useful for renaming a sender or fanning it out. The frame never leaves the GPU: the
+    /// shared texture of `from` is bound with [bind_shared_texture](Self::bind_shared_texture)
+    /// and copied into `to` with [send_texture](Self::send_texture). An OpenGL context must be
+    /// current, e.g. from [create_opengl](Self::create_opengl).
+    ///
+    /// Spout can only be one sender or receiver at a time, so `to` is sent through a second
+    /// `SPOUTLIBRARY` that is created on the first call. Call this once per frame. Returns `false`
+    /// if no frame was received or sent, e.g. while `from` is not running.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn relay(&mut self, from: &str, to: &str) -> Result<bool> {
+        if self.relay_source.as_deref() != Some(from) {
+            self.release_receiver()?;
+            self.set_receiver_name(from)?;
+            self.relay_source = Some(from.to_string());
+        }
+
+        let (name, mut sender) = match self.relay_sender.take() {
+            Some(v) => v,
+            None => {
+                let mut sender = RustySpout::new();
+                sender.get_spout()?;
+
+                (String::new(), Box::new(sender))
+            }
+        };
+        if name != to {
+            sender.release_sender_default()?;
+            sender.set_sender_name(to)?;
+        }
+
+        let sent = self.relay_frame(&mut sender);
+        self.relay_sender = Some((to.to_string(), sender));
+
+        sent
+    }
+
+    /// Receive a single frame into the shared texture and send it through `sender`.
+    fn relay_frame(&mut self, sender: &mut RustySpout) -> Result<bool> {
+        // Without a texture, the shared texture is updated in place
+        if !self.receive_texture(c_uint(0), c_uint(0), false, c_uint(0))? {
+            return Ok(false);
+        }
+
+        let width = self.get_sender_width()?;
+        let height = self.get_sender_height()?;
+
+        if !self.bind_shared_texture()? {
+            return Ok(false);
+        }
+        let sent = self.get_shared_texture_id().and_then(|texture_id| {
+            sender.send_texture(texture_id, GL_TEXTURE_2D, width, height, false, c_uint(0))
+        });
+        self.unbind_shared_texture()?;
+
+        sent
+    }
+
+    /// Get the largest width or height that can be used for a shared texture.
+    ///
+    /// This is the smaller of OpenGL's `GL_MAX_TEXTURE_SIZE` and [DX11_MAX_TEXTURE_SIZE], since
+    /// a shared texture must fit both the DirectX 11 texture and the linked OpenGL texture.
+    ///
+    /// # Safety
+    /// Requires a current OpenGL context, e.g. from [create_opengl](Self::create_opengl). An
+    /// [Error::UnexpectedValue] is returned if there is none.
+    pub fn max_texture_size(&mut self) -> Result<u32> {
+        if self.library.is_none() {
+            return Err(Error::NoHandle);
+        }
+
+        let mut size = 0_i32;
+        unsafe {
+            gl::glGetIntegerv(gl::GL_MAX_TEXTURE_SIZE, &mut size);
+        }
+
+        match u32::try_from(size) {
+            Ok(v) if v > 0 => Ok(v.min(DX11_MAX_TEXTURE_SIZE)),
+            _ => Err(Error::UnexpectedValue {
+                context: "max_texture_size: no current OpenGL context".to_string(),
+            }),
+        }
+    }
+
+    /// Submit all pending OpenGL and DirectX work to the GPU.
+    ///
+    /// Spout flushes its own copies, so this is only needed when the frame is rendered somewhere
+    /// Spout does not know about, e.g. in another OpenGL context that shares textures with the
+    /// sending one, or on another thread. Call it after rendering and before sending, otherwise a
+    /// receiver can read the frame before rendering has finished and see it torn.
+    ///
+    /// `glFlush` is called on the current OpenGL context, if there is one. With the `windows`
+    /// feature, Spout's DX11 immediate context is flushed as well.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    pub fn flush(&mut self) -> Result<()> {
+        if self.library.is_none() {
+            return Err(Error::NoHandle);
+        }
+
+        // Does nothing without a current context
+        unsafe {
+            gl::glFlush();
+        }
+
+        #[cfg(feature = "windows")]
+        if let Ok(context) = self.get_dx11_context_typed() {
+            unsafe {
+                context.Flush();
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn open_directx(&mut self) -> Result<bool> {
         let lib = unsafe { library!(self.library) };
 
@@ -1725,6 +5088,37 @@ impl RustySpout {
         Ok(success)
     }
 
+    /// Open DirectX 11 using an existing device and its immediate context.
+    ///
+    /// `SPOUTLIBRARY` only accepts a device. Spout always uses the device's immediate context, so
+    /// after opening, the context Spout is using is compared against `context`. An [Error] is
+    /// returned if they differ, which happens if `context` is a deferred context or belongs to a
+    /// different device.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// No safety guarantees can be made about the `device` or `context` pointers.
+    pub fn open_directx11_with_context(
+        &mut self,
+        device: *mut c_void,
+        context: *mut c_void,
+    ) -> Result<bool> {
+        if !self.open_directx11(device)? {
+            return Ok(false);
+        }
+
+        if self.get_dx11_context()? != context {
+            return Err(Error::UnexpectedValue {
+                context:
+                    "open_directx11_with_context: context is not the device's immediate context"
+                        .to_string(),
+            });
+        }
+
+        Ok(true)
+    }
+
     pub fn close_directx11(&mut self) -> Result<()> {
         let lib = unsafe { library!(self.library) };
 
@@ -1755,16 +5149,263 @@ impl RustySpout {
         Ok(ptr)
     }
 
+    /// Get the DX11 device used by Spout as an [ID3D11Device].
+    ///
+    /// Spout retains ownership of the device. The returned interface holds its own reference
+    /// (`AddRef` is called), so dropping it does not affect Spout.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// The pointer returned by Spout is assumed to be a valid `ID3D11Device`.
+    #[cfg(feature = "windows")]
+    pub fn get_dx11_device_typed(&mut self) -> Result<ID3D11Device> {
+        let ptr = self.get_dx11_device()?.cast::<std::ffi::c_void>();
+
+        match unsafe { ID3D11Device::from_raw_borrowed(&ptr) } {
+            Some(v) => Ok(v.clone()),
+            None => Err(Error::NullPtr),
+        }
+    }
+
+    /// Get the DX11 immediate context used by Spout as an [ID3D11DeviceContext].
+    ///
+    /// Spout retains ownership of the context. The returned interface holds its own reference
+    /// (`AddRef` is called), so dropping it does not affect Spout.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// The pointer returned by Spout is assumed to be a valid `ID3D11DeviceContext`.
+    #[cfg(feature = "windows")]
+    pub fn get_dx11_context_typed(&mut self) -> Result<ID3D11DeviceContext> {
+        let ptr = self.get_dx11_context()?.cast::<std::ffi::c_void>();
+
+        match unsafe { ID3D11DeviceContext::from_raw_borrowed(&ptr) } {
+            Some(v) => Ok(v.clone()),
+            None => Err(Error::NullPtr),
+        }
+    }
+
+    /// Check that a share handle from [get_handle](Self::get_handle),
+    /// [get_sender_handle](Self::get_sender_handle), or [get_sender_info](Self::get_sender_info)
+    /// still refers to a texture.
+    ///
+    /// Spout's share handles are legacy DXGI shared handles rather than kernel handles, so
+    /// `DuplicateHandle` and `GetHandleInformation` cannot check them. Instead the texture is
+    /// opened on Spout's DX11 device with `OpenSharedResource` and released again. Returns `false`
+    /// for a `nullptr`, if the sender has closed, or if Spout has no DX11 device.
+    ///
+    /// The sender can still close right after this returns `true`, so importing the handle must
+    /// handle failure as well.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    #[cfg(feature = "windows")]
+    pub fn is_handle_valid(&mut self, handle: HANDLE) -> bool {
+        if handle.is_null() {
+            return false;
+        }
+
+        let Ok(device) = self.get_dx11_device_typed() else {
+            return false;
+        };
+
+        let texture: windows::core::Result<ID3D11Texture2D> = unsafe {
+            device.OpenSharedResource(windows::Win32::Foundation::HANDLE(handle as isize))
+        };
+
+        texture.is_ok()
+    }
+
+    /// Get the texture of the connected sender as an [ID3D11Texture2D] on Spout's DX11 device.
+    /// For use when receiving.
+    ///
+    /// `SPOUTLIBRARY` does not expose its internal textures, so the sender's share handle from
+    /// [get_sender_handle](Self::get_sender_handle) is opened with `OpenSharedResource` on the
+    /// device from [get_dx11_device_typed](Self::get_dx11_device_typed). This is the sender's
+    /// own texture, so it can be sampled without any copy, but the sender may be writing to it at
+    /// the same time.
+    ///
+    /// The texture stays valid while the sender keeps it, which ends when
+    /// [is_updated](Self::is_updated) reports a change, the sender closes, or the receiver is
+    /// released. Get it again after any of those.
+    ///
+    /// # Safety
+    /// Guaranteed to have a valid pointer to `SPOUTLIBRARY` as long as the backing struct exists.
+    ///
+    /// An [Error::NullPtr] is returned if there is no connected sender.
+    #[cfg(feature = "windows")]
+    pub fn get_shared_dx11_texture(&mut self) -> Result<ID3D11Texture2D> {
+        let handle = self.get_sender_handle()?;
+        if handle.is_null() {
+            return Err(Error::NullPtr);
+        }
+
+        let device = self.get_dx11_device_typed()?;
+
+        unsafe { device.OpenSharedResource(windows::Win32::Foundation::HANDLE(handle as isize)) }
+            .map_err(|e| Error::UnexpectedValue {
+                context: format!("get_shared_dx11_texture: {e}"),
+            })
+    }
+
+    /// Build a plain-text report of the Spout state, suitable for pasting into an issue.
+    ///
+    /// Any query that fails is reported inline as an `<error: ...>` marker instead of aborting
+    /// the report.
+    pub fn diagnostics(&mut self) -> Result<String> {
+        if self.library.is_none() {
+            return Err(Error::NoHandle);
+        }
+
+        let mut report = String::from("Rusty Spout diagnostics\n");
+
+        match self.versions() {
+            Ok(versions) => {
+                report.push_str(&format!("Crate version: {}\n", versions.crate_version));
+                report.push_str(&format!("SDK version: {}\n", versions.sdk_version));
+                report.push_str(&format!("Spout version: {}\n", versions.spout_version));
+            }
+            Err(e) => {
+                report.push_str(&format!("Crate version: {}\n", env!("CARGO_PKG_VERSION")));
+                report.push_str(&format!("Versions: <error: {e}>\n"));
+            }
+        }
+
+        match self.adapters() {
+            Ok(adapters) => {
+                report.push_str("Adapters:\n");
+                for adapter in adapters {
+                    report.push_str(&format!("  {}: {}\n", adapter.index, adapter.name));
+                }
+            }
+            Err(e) => report.push_str(&format!("Adapters: <error: {e}>\n")),
+        }
+        report.push_str(&format!(
+            "Current adapter: {}\n",
+            or_error(self.get_adapter())
+        ));
+
+        let share_mode = self
+            .get_share_mode()
+            .and_then(ShareMode::try_from)
+            .map(|v| format!("{v:?}"));
+        report.push_str(&format!("Share mode: {}\n", or_error(share_mode)));
+        report.push_str(&format!(
+            "GL/DX ready: {}\n",
+            or_error(self.is_gl_dx_ready())
+        ));
+        report.push_str(&format!("Laptop: {}\n", or_error(self.is_laptop())));
+        report.push_str(&format!(
+            "Auto share: {}\n",
+            or_error(self.get_auto_share())
+        ));
+        report.push_str(&format!("CPU mode: {}\n", or_error(self.get_cpu_mode())));
+        report.push_str(&format!(
+            "Memory share mode: {}\n",
+            or_error(self.get_memory_share_mode())
+        ));
+        report.push_str(&format!("DX9: {}\n", or_error(self.get_dx9())));
+
+        Ok(report)
+    }
+
+    /// Tear down everything in the order Spout expects:
+    /// 1. [release_sender](Self::release_sender)
+    /// 2. [release_receiver](Self::release_receiver)
+    /// 3. [close_opengl](Self::close_opengl)
+    /// 4. [close_directx11](Self::close_directx11)
+    /// 5. [release](Self::release)
+    ///
+    /// Does nothing if the library has already been released. Releasing a sender, receiver,
+    /// OpenGL context, or DirectX device that was never created, or that was already released,
+    /// is not an error, so those steps are not checked.
+    pub fn shutdown(&mut self) -> Result<()> {
+        if self.library.is_none() {
+            return Ok(());
+        }
+
+        self.release_sender_default()?;
+        self.release_receiver()?;
+        // A false return only means there was no OpenGL context to close
+        self.close_opengl()?;
+        self.close_directx11()?;
+
+        self.release()
+    }
+
     pub fn release(&mut self) -> Result<()> {
         let lib = unsafe { library!(self.library) };
 
         lib.Release();
         self.library = None;
+        self.role = None;
+        self.sender_name = None;
+        self.relay_source = None;
+        self.relay_sender = None;
+        self.memory_buffer_name = None;
+        self.log_level = None;
+        self.message_boxes_suppressed = false;
 
         Ok(())
     }
 }
 
+/// A cloneable handle to a single [RustySpout], for handing it to several components.
+///
+/// This is single-threaded only. [RustySpout] is neither [Send] nor [Sync], since Spout's OpenGL
+/// and DirectX resources belong to the thread that created them, so clones share it through an
+/// [Rc] and a [RefCell] and cannot leave that thread. Only one clone can use the underlying
+/// library at a time.
+#[derive(Clone)]
+pub struct SharedSpout {
+    inner: Rc<RefCell<RustySpout>>,
+}
+
+impl From<RustySpout> for SharedSpout {
+    fn from(value: RustySpout) -> Self {
+        Self::new(value)
+    }
+}
+
+impl SharedSpout {
+    /// Wrap a [RustySpout] so it can be shared.
+    pub fn new(spout: RustySpout) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(spout)),
+        }
+    }
+
+    /// Borrow the inner [RustySpout].
+    ///
+    /// An [Error::UnexpectedValue] is returned if another clone is already borrowing it, e.g.
+    /// when called from inside [with](Self::with).
+    pub fn borrow_mut(&self) -> Result<RefMut<'_, RustySpout>> {
+        match self.inner.try_borrow_mut() {
+            Ok(v) => Ok(v),
+            Err(e) => Err(Error::UnexpectedValue {
+                context: format!("SharedSpout::borrow_mut: {e}"),
+            }),
+        }
+    }
+
+    /// Run `f` with the inner [RustySpout] borrowed.
+    pub fn with<R>(&self, f: impl FnOnce(&mut RustySpout) -> Result<R>) -> Result<R> {
+        let mut spout = self.borrow_mut()?;
+
+        f(&mut spout)
+    }
+}
+
+/// Format a value for [RustySpout::diagnostics], replacing errors with a marker.
+fn or_error<T: std::fmt::Display>(value: Result<T>) -> String {
+    match value {
+        Ok(v) => v.to_string(),
+        Err(e) => format!("<error: {e}>"),
+    }
+}
+
 /// Convencience function for pinning a pointer.
 ///
 /// # Safety
@@ -1772,3 +5413,509 @@ impl RustySpout {
 unsafe fn as_pin<'a>(ptr: *mut ffi::SPOUTLIBRARY) -> Pin<&'a mut ffi::SPOUTLIBRARY> {
     Pin::new_unchecked(&mut *ptr)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_receiver_name_is_nullptr() {
+        let name = receiver_name("test", "").unwrap();
+
+        assert!(name.is_none());
+        assert!(name_ptr(&name).is_null());
+    }
+
+    #[test]
+    fn receiver_name_is_passed_through() {
+        let name = receiver_name("test", "sender").unwrap();
+
+        assert_eq!(name, Some(CString::new("sender").unwrap()));
+        assert!(!name_ptr(&name).is_null());
+    }
+
+    #[test]
+    fn receiver_name_with_nul_is_rejected() {
+        assert!(matches!(
+            receiver_name("test", "send\0er"),
+            Err(Error::FfiTypeInto { .. })
+        ));
+    }
+
+    #[test]
+    fn updated_receive_is_not_a_frame() {
+        let mut last = None;
+
+        assert!(!accept_frame(ReceiveStatus::Updated, Some(1), &mut last));
+        assert!(!accept_frame(
+            ReceiveStatus::NotConnected,
+            Some(1),
+            &mut last
+        ));
+        assert_eq!(last, None);
+    }
+
+    #[test]
+    fn received_frames_are_returned_once() {
+        let mut last = None;
+
+        assert!(!accept_frame(ReceiveStatus::Received, None, &mut last));
+        assert!(accept_frame(ReceiveStatus::Received, Some(1), &mut last));
+        assert!(!accept_frame(ReceiveStatus::Received, Some(1), &mut last));
+        assert!(accept_frame(ReceiveStatus::Received, Some(2), &mut last));
+        assert_eq!(last, Some(2));
+    }
+
+    #[test]
+    fn frame_0_is_always_new() {
+        let mut last = None;
+
+        assert!(accept_frame(ReceiveStatus::Received, Some(0), &mut last));
+        assert!(accept_frame(ReceiveStatus::Received, Some(0), &mut last));
+    }
+
+    // recv goes through receive_image_if_new, so an updated receive is never a frame either
+    #[test]
+    fn recv_skips_updated_receive() {
+        let mut last = Some(1);
+
+        assert!(!accept_frame(ReceiveStatus::Updated, Some(2), &mut last));
+        assert_eq!(last, Some(1));
+    }
+
+    #[test]
+    fn recv_keeps_buffer_on_error() {
+        let mut spout = RustySpout::new();
+        spout.recv_buffer = vec![1, 2, 3];
+
+        assert!(matches!(spout.recv(), Err(Error::NoHandle)));
+        assert_eq!(spout.recv_buffer, [1, 2, 3]);
+    }
+
+    #[test]
+    fn bounded_cstr_stops_at_nul() {
+        let buf = *b"sender\0garbage";
+        let value = unsafe { read_bounded_cstr(buf.as_ptr().cast(), buf.len()) };
+
+        assert_eq!(value.unwrap(), "sender");
+    }
+
+    #[test]
+    fn bounded_cstr_without_nul_is_an_error() {
+        // The nul is just past `max`, so it must not be read
+        let buf = *b"sender\0";
+        let value = unsafe { read_bounded_cstr(buf.as_ptr().cast(), 6) };
+
+        assert!(matches!(value, Err(Error::FfiTypeFrom { .. })));
+    }
+
+    #[test]
+    fn bounded_cstr_nullptr() {
+        let value = unsafe { read_bounded_cstr(std::ptr::null(), MAX_SENDER_NAME_LEN) };
+
+        assert!(matches!(value, Err(Error::NullPtr)));
+    }
+
+    #[test]
+    fn bounded_cstr_max_0() {
+        let buf = *b"\0";
+        let value = unsafe { read_bounded_cstr(buf.as_ptr().cast(), 0) };
+
+        assert!(matches!(value, Err(Error::FfiTypeFrom { .. })));
+    }
+
+    #[test]
+    fn cstring_cache_reuses_allocations() {
+        let mut spout = RustySpout::new();
+
+        let first = spout.cached_cstring("test", "sender").unwrap().as_ptr();
+        for _ in 0..10_000 {
+            let ptr = spout.cached_cstring("test", "sender").unwrap().as_ptr();
+            assert_eq!(ptr, first);
+        }
+        assert_eq!(spout.cstring_cache.len(), 1);
+
+        spout.clear_cstring_cache();
+        assert!(spout.cstring_cache.is_empty());
+    }
+
+    #[test]
+    fn cstring_cache_evicts_least_recently_used() {
+        let mut spout = RustySpout::new();
+
+        for i in 0..CSTRING_CACHE_CAPACITY {
+            spout.cached_cstring("test", &i.to_string()).unwrap();
+        }
+        // Using the oldest entry makes "1" the least recently used
+        spout.cached_cstring("test", "0").unwrap();
+        spout.cached_cstring("test", "new").unwrap();
+
+        let keys = spout
+            .cstring_cache
+            .iter()
+            .map(|(k, _)| k.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(keys.len(), CSTRING_CACHE_CAPACITY);
+        assert!(keys.contains(&"0"));
+        assert!(!keys.contains(&"1"));
+        assert_eq!(keys.last(), Some(&"new"));
+    }
+
+    #[test]
+    fn cstring_cache_rejects_nul() {
+        let mut spout = RustySpout::new();
+
+        assert!(matches!(
+            spout.cached_cstring("test", "send\0er"),
+            Err(Error::FfiTypeInto { .. })
+        ));
+        assert!(spout.cstring_cache.is_empty());
+    }
+
+    // Without a handle, a value that passes the range check fails with Error::NoHandle instead
+    #[cfg(feature = "log")]
+    #[test]
+    fn spout_log_levels() {
+        let cases = [
+            ("[verbose] a", log::Level::Debug, "a"),
+            ("[notice] a", log::Level::Info, "a"),
+            ("[warning] a", log::Level::Warn, "a"),
+            ("[error] a", log::Level::Error, "a"),
+            ("[fatal] a", log::Level::Error, "a"),
+            ("[ Warning ]   a\r\n", log::Level::Warn, "a"),
+            ("[WARNING]a", log::Level::Warn, "a"),
+        ];
+
+        for (line, level, message) in cases {
+            assert_eq!(parse_spout_log_line(line), (level, message), "{line:?}");
+        }
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn unprefixed_spout_log_lines_are_info() {
+        let cases = ["a", "[unknown] a", "[warning a", " [warning] a", ""];
+
+        for line in cases {
+            assert_eq!(
+                parse_spout_log_line(line),
+                (log::Level::Info, line),
+                "{line:?}"
+            );
+        }
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn spout_log_cursor_skips_partial_line() {
+        let mut cursor = 0;
+        let log = "[notice] a\n[warning] b";
+        assert_eq!(
+            new_spout_log_lines(log, &mut cursor).collect::<Vec<_>>(),
+            ["[notice] a"]
+        );
+        assert_eq!(cursor, 11);
+
+        // Nothing new until the line is finished
+        assert_eq!(new_spout_log_lines(log, &mut cursor).count(), 0);
+        assert_eq!(cursor, 11);
+
+        let log = "[notice] a\n[warning] b\r\n\n[error] c\n";
+        assert_eq!(
+            new_spout_log_lines(log, &mut cursor).collect::<Vec<_>>(),
+            ["[warning] b", "[error] c"]
+        );
+        assert_eq!(cursor, log.len());
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn spout_log_cursor_resets_on_truncation() {
+        let mut cursor = 100;
+        let log = "[notice] a\n";
+        assert_eq!(
+            new_spout_log_lines(log, &mut cursor).collect::<Vec<_>>(),
+            ["[notice] a"]
+        );
+        assert_eq!(cursor, log.len());
+
+        // Not on a char boundary
+        let mut cursor = 1;
+        let log = "ü\n";
+        assert_eq!(
+            new_spout_log_lines(log, &mut cursor).collect::<Vec<_>>(),
+            ["ü"]
+        );
+    }
+
+    #[test]
+    fn compatible_dxgi_formats() {
+        let cases = [
+            // Spout default, B8G8R8A8 and B8G8R8X8 with their typeless and SRGB variants
+            (0, 87),
+            (87, 87),
+            (87, 88),
+            (87, 90),
+            (87, 91),
+            (88, 93),
+            (92, 0),
+            // R8G8B8A8
+            (28, 28),
+            (27, 28),
+            (28, 29),
+            (27, 29),
+            // R10G10B10A2, R16G16B16A16 and R32G32B32A32
+            (23, 24),
+            (9, 10),
+            (9, 11),
+            (1, 2),
+        ];
+
+        for (a, b) in cases {
+            assert!(dxgi_formats_compatible(c_ulong(a), c_ulong(b)), "{a} {b}");
+            assert!(dxgi_formats_compatible(c_ulong(b), c_ulong(a)), "{b} {a}");
+        }
+    }
+
+    #[test]
+    fn incompatible_dxgi_formats() {
+        let cases = [
+            // BGRA and RGBA
+            (87, 28),
+            (0, 28),
+            (91, 29),
+            (90, 27),
+            // Different channel sizes
+            (28, 24),
+            (10, 2),
+            // Float and UNORM of the same family
+            (10, 11),
+        ];
+
+        for (a, b) in cases {
+            assert!(!dxgi_formats_compatible(c_ulong(a), c_ulong(b)), "{a} {b}");
+            assert!(!dxgi_formats_compatible(c_ulong(b), c_ulong(a)), "{b} {a}");
+        }
+    }
+
+    #[test]
+    fn valid_sender_names() {
+        for name in [
+            "Sender",
+            "My Sender (2)",
+            "Sender/1",
+            "Sender ü",
+            &"a".repeat(255),
+        ] {
+            assert!(validate_sender_name(name).is_ok(), "{name:?}");
+        }
+    }
+
+    #[test]
+    fn invalid_sender_names() {
+        for name in [
+            "",
+            &"a".repeat(256),
+            &"a".repeat(1000),
+            "Sen\0der",
+            "Sender\0",
+            "Sen\\der",
+            "Sen\nder",
+        ] {
+            assert!(
+                matches!(
+                    validate_sender_name(name),
+                    Err(Error::UnexpectedValue { .. })
+                ),
+                "{name:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn sender_name_length_is_in_bytes() {
+        // 128 two-byte characters are 256 bytes
+        let name = "ü".repeat(128);
+        assert_eq!(name.chars().count(), 128);
+        assert!(validate_sender_name(&name).is_err());
+        assert!(validate_sender_name(&name[2..]).is_ok());
+    }
+
+    #[test]
+    fn parse_spout_versions() {
+        let cases = [
+            ("2.007.011", Some((2, 7, 11))),
+            (" 2.007.011\n", Some((2, 7, 11))),
+            ("v2.007.011", Some((2, 7, 11))),
+            ("V2.007", Some((2, 7, 0))),
+            ("2.007", Some((2, 7, 0))),
+            ("2", None),
+            ("", None),
+            ("v", None),
+            ("2.007.011.1", None),
+            ("2..011", None),
+            ("2.007.", None),
+            ("2.007.011-beta", None),
+            ("-2.007.011", None),
+            ("vv2.007.011", None),
+            ("two.007.011", None),
+        ];
+
+        for (version, expected) in cases {
+            assert_eq!(parse_spout_version(version), expected, "{version:?}");
+        }
+    }
+
+    #[test]
+    fn compare_spout_version_table() {
+        use std::cmp::Ordering::*;
+
+        let built = (2, 7, 11);
+        let cases = [
+            // The library version decides unless it matches
+            ((2, 7, 11), 0, Equal),
+            ((2, 7, 10), 0, Less),
+            ((2, 7, 12), 0, Greater),
+            ((2, 6, 99), 2007, Less),
+            ((3, 0, 0), 2007, Greater),
+            // Then the installed release, ignoring its patch level
+            ((2, 7, 11), 2007, Equal),
+            ((2, 7, 11), 2006, Less),
+            ((2, 7, 11), 2008, Greater),
+            ((2, 7, 11), 2007010, Equal),
+            ((2, 7, 11), 2006011, Less),
+            ((2, 7, 11), 3000000, Greater),
+            // Not installed
+            ((2, 7, 11), -1, Equal),
+        ];
+
+        for (sdk, spout_version, expected) in cases {
+            assert_eq!(
+                compare_spout_versions(built, sdk, spout_version),
+                expected,
+                "{sdk:?} {spout_version}"
+            );
+        }
+    }
+
+    #[test]
+    fn frame_counter_starts_at_first_frame() {
+        let mut counter = FrameCounter::default();
+        assert_eq!(counter.update(42), 42);
+        assert_eq!(counter.update(43), 43);
+    }
+
+    #[test]
+    fn frame_counter_counts_skipped_frames() {
+        let mut counter = FrameCounter::default();
+        counter.update(10);
+        assert_eq!(counter.update(15), 15);
+        assert_eq!(counter.update(15), 15);
+    }
+
+    #[test]
+    fn frame_counter_continues_past_i32_max() {
+        let mut counter = FrameCounter::default();
+        counter.update(i32::MAX);
+        assert_eq!(counter.update(i32::MIN), i32::MAX as u64 + 1);
+        assert_eq!(counter.update(i32::MIN + 1), i32::MAX as u64 + 2);
+    }
+
+    #[test]
+    fn frame_counter_continues_after_sender_restart() {
+        let mut counter = FrameCounter::default();
+        counter.update(1000);
+        assert_eq!(counter.update(1), 1001);
+        assert_eq!(counter.update(3), 1003);
+    }
+
+    #[test]
+    fn frame_counter_stays_0_when_disabled() {
+        let mut counter = FrameCounter::default();
+        assert_eq!(counter.update(0), 0);
+        assert_eq!(counter.update(0), 0);
+    }
+
+    #[test]
+    fn empty_frame_is_blank() {
+        assert!(is_frame_blank(&[]));
+    }
+
+    #[test]
+    fn zeroed_frame_is_blank() {
+        assert!(is_frame_blank(&[0; 4]));
+        assert!(is_frame_blank(&vec![0; 1920 * 1080 * 4]));
+    }
+
+    #[test]
+    fn opaque_black_frame_is_not_blank() {
+        assert!(!is_frame_blank(&[0, 0, 0, 255]));
+        assert!(!is_frame_blank(&[0, 0, 0, 255].repeat(1920 * 1080)));
+    }
+
+    #[test]
+    fn nonzero_last_byte_is_not_blank() {
+        for len in [1, 4096, 4097, 8191, 1920 * 1080 * 3] {
+            let mut pixels = vec![0; len];
+            *pixels.last_mut().unwrap() = 1;
+            assert!(!is_frame_blank(&pixels), "{len}");
+        }
+    }
+
+    #[test]
+    fn set_max_senders_bounds() {
+        let mut spout = RustySpout::new();
+
+        assert!(matches!(
+            spout.set_max_senders(0),
+            Err(Error::UnexpectedValue { .. })
+        ));
+        assert!(matches!(
+            spout.set_max_senders(MIN_MAX_SENDERS),
+            Err(Error::NoHandle)
+        ));
+        assert!(matches!(
+            spout.set_max_senders(MAX_MAX_SENDERS),
+            Err(Error::NoHandle)
+        ));
+        assert!(matches!(
+            spout.set_max_senders(MAX_MAX_SENDERS + 1),
+            Err(Error::UnexpectedValue { .. })
+        ));
+    }
+
+    #[test]
+    fn log_file_is_not_created_without_handle() {
+        let dir = std::env::temp_dir().join(format!("rusty_spout_log_{}", std::process::id()));
+        let path = dir.join("spout.log");
+
+        let mut spout = RustySpout::new();
+        assert!(matches!(
+            spout.enable_spout_log_file(path.to_str().unwrap(), false),
+            Err(Error::NoHandle)
+        ));
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn set_buffers_bounds() {
+        let mut spout = RustySpout::new();
+
+        assert!(matches!(
+            spout.set_buffers(MIN_BUFFERS - 1),
+            Err(Error::UnexpectedValue { .. })
+        ));
+        assert!(matches!(
+            spout.set_buffers(MIN_BUFFERS),
+            Err(Error::NoHandle)
+        ));
+        assert!(matches!(
+            spout.set_buffers(MAX_BUFFERS),
+            Err(Error::NoHandle)
+        ));
+        assert!(matches!(
+            spout.set_buffers(MAX_BUFFERS + 1),
+            Err(Error::UnexpectedValue { .. })
+        ));
+    }
+}
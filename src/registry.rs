@@ -0,0 +1,118 @@
+//! Minimal registry access for finding Spout's installed tools.
+//!
+//! `SPOUTLIBRARY`'s registry functions take an `HKEY`, which is not bindable, so the few values
+//! needed are read with `RegGetValueA` directly.
+
+use std::{
+    ffi::{c_char, c_void, CStr, CString},
+    io,
+    path::PathBuf,
+};
+
+/// `HKEY_CURRENT_USER`, sign extended like the Windows headers do.
+const HKEY_CURRENT_USER: isize = 0x8000_0001_u32 as i32 as isize;
+/// `RRF_RT_REG_SZ`, only accept string values.
+const RRF_RT_REG_SZ: u32 = 0x0000_0002;
+const ERROR_SUCCESS: i32 = 0;
+const ERROR_FILE_NOT_FOUND: i32 = 2;
+
+/// The key that SpoutPanel and SpoutSettings register themselves under.
+pub(crate) const SPOUT_PANEL_SUB_KEY: &str = "Software\\Leading Edge\\SpoutPanel";
+
+#[cfg(windows)]
+#[link(name = "advapi32")]
+extern "system" {
+    fn RegGetValueA(
+        hkey: isize,
+        lpsubkey: *const c_char,
+        lpvalue: *const c_char,
+        dwflags: u32,
+        pdwtype: *mut u32,
+        pvdata: *mut c_void,
+        pcbdata: *mut u32,
+    ) -> i32;
+}
+
+/// There is no registry off Windows, so nothing is ever found.
+#[cfg(not(windows))]
+#[allow(non_snake_case)]
+unsafe fn RegGetValueA(
+    _hkey: isize,
+    _lpsubkey: *const c_char,
+    _lpvalue: *const c_char,
+    _dwflags: u32,
+    _pdwtype: *mut u32,
+    _pvdata: *mut c_void,
+    _pcbdata: *mut u32,
+) -> i32 {
+    ERROR_FILE_NOT_FOUND
+}
+
+/// Convert a registry status code into a result. A missing key or value is `Ok(false)`.
+fn check_status(status: i32) -> io::Result<bool> {
+    match status {
+        ERROR_SUCCESS => Ok(true),
+        ERROR_FILE_NOT_FOUND => Ok(false),
+        _ => Err(io::Error::from_raw_os_error(status)),
+    }
+}
+
+/// Read a string value from `HKEY_CURRENT_USER`. Returns `None` if the value does not exist.
+pub(crate) fn read_string(sub_key: &str, value_name: &str) -> io::Result<Option<String>> {
+    let sub_key = CString::new(sub_key)?;
+    let value_name = CString::new(value_name)?;
+
+    let mut size = 0_u32;
+    let status = unsafe {
+        RegGetValueA(
+            HKEY_CURRENT_USER,
+            sub_key.as_ptr(),
+            value_name.as_ptr(),
+            RRF_RT_REG_SZ,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            &mut size,
+        )
+    };
+    if !check_status(status)? || size == 0 {
+        return Ok(None);
+    }
+
+    let mut buffer = vec![0_u8; size as usize];
+    let status = unsafe {
+        RegGetValueA(
+            HKEY_CURRENT_USER,
+            sub_key.as_ptr(),
+            value_name.as_ptr(),
+            RRF_RT_REG_SZ,
+            std::ptr::null_mut(),
+            buffer.as_mut_ptr().cast(),
+            &mut size,
+        )
+    };
+    if !check_status(status)? {
+        return Ok(None);
+    }
+
+    let value = CStr::from_bytes_until_nul(buffer.as_slice())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let value = value
+        .to_str()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    Ok(Some(value.to_string()))
+}
+
+/// The path to `SpoutPanel.exe` if it is registered and exists on disk.
+pub(crate) fn spout_panel_path() -> io::Result<Option<PathBuf>> {
+    let path = match read_string(SPOUT_PANEL_SUB_KEY, "InstallPath")? {
+        Some(v) => PathBuf::from(v),
+        None => return Ok(None),
+    };
+
+    if path.is_file() {
+        Ok(Some(path))
+    } else {
+        Ok(None)
+    }
+}
@@ -0,0 +1,183 @@
+//! Role-specific views of a [RustySpout].
+//!
+//! `SPOUTLIBRARY` has two nearly identical sets of getters. The unprefixed ones (`GetWidth`,
+//! `GetFps`, ...) describe the sender created by this application, while the `GetSender*` ones
+//! describe the sender that this application is receiving from. [Sender] and [Receiver] only
+//! expose the set that applies to their role.
+
+use crate::{Error, Result, RustySpout, DWORD, HANDLE};
+
+/// A view of a [RustySpout] that is sending.
+pub struct Sender<'a> {
+    pub(crate) spout: &'a mut RustySpout,
+    /// Call `release_sender_default` when dropped.
+    pub(crate) release_on_drop: bool,
+}
+
+impl Drop for Sender<'_> {
+    fn drop(&mut self) {
+        if self.release_on_drop {
+            // There is no way to report an error from drop, and Spout cleans up when the
+            // library is released anyway
+            let _ = self.spout.release_sender_default();
+        }
+    }
+}
+
+impl Sender<'_> {
+    /// The underlying [RustySpout].
+    pub fn spout(&mut self) -> &mut RustySpout {
+        self.spout
+    }
+
+    /// Close the sender with [RustySpout::release_sender_default] when this view is dropped.
+    ///
+    /// Off by default, since views are usually short-lived and the sender outlives them.
+    pub fn release_on_drop(mut self) -> Self {
+        self.release_on_drop = true;
+        self
+    }
+
+    /// See [RustySpout::get_name].
+    pub fn name(&mut self) -> Result<String> {
+        self.spout.get_name()
+    }
+
+    /// See [RustySpout::get_width].
+    pub fn width(&mut self) -> Result<u32> {
+        self.spout.get_width()
+    }
+
+    /// See [RustySpout::get_height].
+    pub fn height(&mut self) -> Result<u32> {
+        self.spout.get_height()
+    }
+
+    /// See [RustySpout::get_fps].
+    pub fn fps(&mut self) -> Result<f64> {
+        self.spout.get_fps()
+    }
+
+    /// See [RustySpout::get_frame].
+    pub fn frame(&mut self) -> Result<i32> {
+        self.spout.get_frame()
+    }
+
+    /// See [RustySpout::get_handle].
+    pub fn handle(&mut self) -> Result<HANDLE> {
+        self.spout.get_handle()
+    }
+
+    /// See [RustySpout::get_cpu].
+    pub fn cpu(&mut self) -> Result<bool> {
+        self.spout.get_cpu()
+    }
+
+    /// See [RustySpout::get_gl_dx].
+    pub fn gl_dx(&mut self) -> Result<bool> {
+        self.spout.get_gl_dx()
+    }
+
+    /// Lock the sender to `target` frames per second.
+    ///
+    /// Frame counting is enabled so that [FrameRateControl::achieved] can be measured. An
+    /// [Error::UnexpectedValue] is returned if `target` is not positive.
+    pub fn frame_rate_control(&mut self, target: i32) -> Result<FrameRateControl<'_>> {
+        if target <= 0 {
+            return Err(Error::UnexpectedValue {
+                context: format!("frame_rate_control: target must be positive, got {target}"),
+            });
+        }
+
+        self.spout.set_frame_count(true)?;
+
+        Ok(FrameRateControl {
+            spout: self.spout,
+            target,
+        })
+    }
+}
+
+/// Frame pacing for a [Sender], keeping the requested and achieved frame rates together.
+pub struct FrameRateControl<'a> {
+    spout: &'a mut RustySpout,
+    target: i32,
+}
+
+impl FrameRateControl<'_> {
+    /// The requested frames per second.
+    pub fn target(&self) -> i32 {
+        self.target
+    }
+
+    /// Wait until it is time for the next frame. Call once per frame, after sending.
+    ///
+    /// See [RustySpout::hold_fps].
+    pub fn hold(&mut self) -> Result<()> {
+        self.spout.hold_fps(self.target)
+    }
+
+    /// The frame rate measured by Spout.
+    ///
+    /// See [RustySpout::get_fps].
+    pub fn achieved(&mut self) -> Result<f64> {
+        self.spout.get_fps()
+    }
+}
+
+/// A view of a [RustySpout] that is receiving.
+pub struct Receiver<'a> {
+    pub(crate) spout: &'a mut RustySpout,
+}
+
+impl Receiver<'_> {
+    /// The underlying [RustySpout].
+    pub fn spout(&mut self) -> &mut RustySpout {
+        self.spout
+    }
+
+    /// See [RustySpout::get_sender_name].
+    pub fn sender_name(&mut self) -> Result<String> {
+        self.spout.get_sender_name()
+    }
+
+    /// See [RustySpout::get_sender_width].
+    pub fn width(&mut self) -> Result<u32> {
+        self.spout.get_sender_width()
+    }
+
+    /// See [RustySpout::get_sender_height].
+    pub fn height(&mut self) -> Result<u32> {
+        self.spout.get_sender_height()
+    }
+
+    /// See [RustySpout::get_sender_format].
+    pub fn format(&mut self) -> Result<DWORD> {
+        self.spout.get_sender_format()
+    }
+
+    /// See [RustySpout::get_sender_fps].
+    pub fn fps(&mut self) -> Result<f64> {
+        self.spout.get_sender_fps()
+    }
+
+    /// See [RustySpout::get_sender_frame].
+    pub fn frame(&mut self) -> Result<i32> {
+        self.spout.get_sender_frame()
+    }
+
+    /// See [RustySpout::get_sender_handle].
+    pub fn handle(&mut self) -> Result<HANDLE> {
+        self.spout.get_sender_handle()
+    }
+
+    /// See [RustySpout::get_sender_cpu].
+    pub fn cpu(&mut self) -> Result<bool> {
+        self.spout.get_sender_cpu()
+    }
+
+    /// See [RustySpout::get_sender_gl_dx].
+    pub fn gl_dx(&mut self) -> Result<bool> {
+        self.spout.get_sender_gl_dx()
+    }
+}
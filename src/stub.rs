@@ -0,0 +1,226 @@
+//! Stand-in for the autocxx bindings on platforms other than Windows.
+//!
+//! Spout is Windows-only, so this only exists to let downstream crates type-check elsewhere.
+//! [GetSpout] always returns a `nullptr`, so [RustySpout::get_spout](crate::RustySpout::get_spout)
+//! fails with [Error::NoHandle](crate::Error::NoHandle) and none of the methods below are ever
+//! reached. Their signatures mirror what autocxx generates from `SpoutLibrary.h`.
+
+#![allow(non_snake_case, non_camel_case_types, clippy::too_many_arguments)]
+
+use std::{ffi::c_char, pin::Pin};
+
+use autocxx::prelude::*;
+use cxx::{CxxString, UniquePtr};
+
+pub type HANDLE = *mut c_void;
+type GLuint = c_uint;
+type GLenum = c_uint;
+type DWORD = c_ulong;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpoutLibLogLevel {
+    SPOUT_LOG_SILENT,
+    SPOUT_LOG_VERBOSE,
+    SPOUT_LOG_NOTICE,
+    SPOUT_LOG_WARNING,
+    SPOUT_LOG_ERROR,
+    SPOUT_LOG_FATAL,
+    SPOUT_LOG_NONE,
+}
+
+pub struct SPOUTLIBRARY {
+    _private: (),
+}
+
+/// Always returns a `nullptr`, since there is no Spout to load.
+pub fn GetSpout() -> *mut SPOUTLIBRARY {
+    std::ptr::null_mut()
+}
+
+/// Declare methods on [SPOUTLIBRARY] that can never be called.
+macro_rules! stub_methods {
+    ($($(#[$unsafe:ident])? fn $name:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)?;)*) => {
+        impl SPOUTLIBRARY {
+            $(
+                stub_methods!(@method $($unsafe)? $name($($arg: $ty),*) $(-> $ret)?);
+            )*
+        }
+    };
+    (@method unsafe $name:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)?) => {
+        #[allow(clippy::missing_safety_doc)]
+        pub unsafe fn $name(self: Pin<&mut Self>, $(_: $ty),*) $(-> $ret)? {
+            unreachable!("the stub SPOUTLIBRARY is never created")
+        }
+    };
+    (@method $name:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)?) => {
+        pub fn $name(self: Pin<&mut Self>, $(_: $ty),*) $(-> $ret)? {
+            unreachable!("the stub SPOUTLIBRARY is never created")
+        }
+    };
+}
+
+stub_methods! {
+    #[unsafe] fn SetSenderName(name: *const c_char);
+    fn SetSenderFormat(format: DWORD);
+    fn ReleaseSender(msec: DWORD);
+    fn SendFbo(fbo_id: GLuint, width: c_uint, height: c_uint, invert: bool) -> bool;
+    fn SendTexture(
+        texture_id: GLuint,
+        texture_target: GLuint,
+        width: c_uint,
+        height: c_uint,
+        invert: bool,
+        host_fbo: GLuint
+    ) -> bool;
+    #[unsafe] fn SendImage(
+        pixels: *const u8,
+        width: c_uint,
+        height: c_uint,
+        gl_format: GLenum,
+        invert: bool
+    ) -> bool;
+    fn GetName() -> *const c_char;
+    fn GetWidth() -> c_uint;
+    fn GetHeight() -> c_uint;
+    fn GetFps() -> f64;
+    fn GetFrame() -> c_int;
+    fn GetHandle() -> HANDLE;
+    fn GetCPU() -> bool;
+    fn GetGLDX() -> bool;
+
+    #[unsafe] fn SetReceiverName(sender_name: *const c_char);
+    fn ReleaseReceiver();
+    fn ReceiveTexture(
+        texture_id: GLuint,
+        texture_target: GLuint,
+        invert: bool,
+        host_fbo: GLuint
+    ) -> bool;
+    #[unsafe] fn ReceiveImage(pixels: *mut u8, gl_format: GLenum, invert: bool, host_fbo: GLuint) -> bool;
+    fn IsUpdated() -> bool;
+    fn IsConnected() -> bool;
+    fn IsFrameNew() -> bool;
+    fn GetSenderName() -> *const c_char;
+    fn GetSenderWidth() -> c_uint;
+    fn GetSenderHeight() -> c_uint;
+    fn GetSenderFormat() -> DWORD;
+    fn GetSenderFps() -> f64;
+    fn GetSenderFrame() -> c_int;
+    fn GetSenderHandle() -> HANDLE;
+    fn GetSenderCPU() -> bool;
+    fn GetSenderGLDX() -> bool;
+    fn SelectSender();
+
+    fn SetFrameCount(enable: bool);
+    fn DisableFrameCount();
+    fn IsFrameCountEnabled() -> bool;
+    fn HoldFps(fps: c_int);
+    fn GetRefreshRate() -> f64;
+    #[unsafe] fn SetFrameSync(sender_name: *const c_char);
+    #[unsafe] fn WaitFrameSync(sender_name: *const c_char, timeout: DWORD) -> bool;
+
+    #[unsafe] fn WriteMemoryBuffer(sender_name: *const c_char, data: *const c_char, length: c_int) -> bool;
+    #[unsafe] fn ReadMemoryBuffer(sender_name: *const c_char, data: *mut c_char, max_length: c_int) -> c_int;
+    #[unsafe] fn CreateMemoryBuffer(name: *const c_char, length: c_int) -> bool;
+    fn DeleteMemoryBuffer() -> bool;
+    #[unsafe] fn GetMemoryBufferSize(name: *const c_char) -> c_int;
+
+    fn OpenSpoutConsole();
+    fn CloseSpoutConsole(warning: bool);
+    fn EnableSpoutLog();
+    #[unsafe] fn EnableSpoutLogFile(filename: *const c_char, append: bool);
+    fn GetSpoutLog() -> UniquePtr<CxxString>;
+    fn ShowSpoutLogs();
+    fn DisableSpoutLog();
+    fn SetSpoutLogLevel(level: SpoutLibLogLevel);
+    #[unsafe] fn SpoutMessageBox(message: *const c_char, milliseconds: DWORD) -> c_int;
+
+    fn GetSDKversion() -> UniquePtr<CxxString>;
+    fn IsLaptop() -> bool;
+    fn StartTiming();
+    fn EndTiming() -> f64;
+
+    fn IsInitialized() -> bool;
+    fn BindSharedTexture() -> bool;
+    fn UnBindSharedTexture() -> bool;
+    fn GetSharedTextureID() -> GLuint;
+
+    fn GetSenderCount() -> c_int;
+    #[unsafe] fn GetSender(index: c_int, sender_name: *mut c_char, max_size: c_int) -> bool;
+    #[unsafe] fn FindSenderName(sender_name: *const c_char) -> bool;
+    #[unsafe] fn GetSenderInfo(
+        sender_name: *const c_char,
+        width: &mut c_uint,
+        height: &mut c_uint,
+        share_handle: &mut HANDLE,
+        format: &mut DWORD
+    ) -> bool;
+    #[unsafe] fn GetActiveSender(sender_name: *mut c_char) -> bool;
+    #[unsafe] fn SetActiveSender(sender_name: *const c_char) -> bool;
+
+    fn GetBufferMode() -> bool;
+    fn SetBufferMode(active: bool);
+    fn GetBuffers() -> c_int;
+    fn SetBuffers(buffers: c_int);
+    fn GetMaxSenders() -> c_int;
+    fn SetMaxSenders(max_senders: c_int);
+
+    #[unsafe] fn CreateSender(sender_name: *const c_char, width: c_uint, height: c_uint, format: DWORD) -> bool;
+    #[unsafe] fn UpdateSender(sender_name: *const c_char, width: c_uint, height: c_uint) -> bool;
+    #[unsafe] fn CreateReceiver(
+        sender_name: *mut c_char,
+        width: &mut c_uint,
+        height: &mut c_uint,
+        use_active: bool
+    ) -> bool;
+
+    fn GetDX9() -> bool;
+    fn SetDX9(dx9: bool) -> bool;
+    fn GetMemoryShareMode() -> bool;
+    fn SetMemoryShareMode(mem: bool) -> bool;
+    fn GetCPUmode() -> bool;
+    fn SetCPUmode(cpu: bool) -> bool;
+    fn GetShareMode() -> c_int;
+    fn SetShareMode(mode: c_int);
+
+    fn SelectSenderPanel();
+    #[unsafe] fn GetHostPath(sender_name: *const c_char, host_path: *mut c_char, max_chars: c_int) -> bool;
+    fn GetVerticalSync() -> c_int;
+    fn SetVerticalSync(sync: bool) -> bool;
+    fn GetSpoutVersion() -> c_int;
+    fn GetAutoShare() -> bool;
+    fn SetAutoShare(auto: bool);
+    fn IsGLDXready() -> bool;
+
+    fn GetNumAdapters() -> c_int;
+    #[unsafe] fn GetAdapterName(index: c_int, adapter_name: *mut c_char, max_chars: c_int) -> bool;
+    fn GetAdapter() -> c_int;
+    #[unsafe] fn GetPerformancePreference(path: *const c_char) -> c_int;
+    #[unsafe] fn SetPerformancePreference(preference: c_int, path: *const c_char) -> bool;
+    #[unsafe] fn GetPreferredAdapterName(preference: c_int, adapter_name: *mut c_char, max_chars: c_int) -> bool;
+    fn SetPreferredAdapter(preference: c_int) -> bool;
+    fn IsPreferenceAvailable() -> bool;
+    #[unsafe] fn IsApplicationPath(path: *const c_char) -> bool;
+
+    fn CreateOpenGL() -> bool;
+    fn CloseOpenGL() -> bool;
+    fn CopyTexture(
+        source_id: GLuint,
+        source_target: GLuint,
+        dest_id: GLuint,
+        dest_target: GLuint,
+        width: c_uint,
+        height: c_uint,
+        invert: bool,
+        host_fbo: GLuint
+    ) -> bool;
+
+    fn OpenDirectX() -> bool;
+    fn CloseDirectX();
+    #[unsafe] fn OpenDirectX11(device: *mut c_void) -> bool;
+    fn CloseDirectX11();
+    fn GetDX11Device() -> *mut c_void;
+    fn GetDX11Context() -> *mut c_void;
+
+    fn Release();
+}